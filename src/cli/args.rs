@@ -7,6 +7,58 @@ pub struct Cli {
     /// Display style
     #[arg(short = 's', long = "style", value_enum, default_value_t = DisplayStyle::Default)]
     pub style: DisplayStyle,
+
+    /// Output format
+    #[arg(short = 'f', long = "format", value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+
+    /// Sampling interval of network and disk throughput rates, in milliseconds
+    #[arg(long = "interval", default_value_t = 1000)]
+    pub interval: u64,
+
+    /// Hide loopback and virtual network interfaces (docker, veth, bridges)
+    #[arg(long = "hide-virtual")]
+    pub hide_virtual: bool,
+
+    /// Package managers left out of the package counts, separated by commas
+    #[arg(long = "hide-packages", value_enum, value_delimiter = ',')]
+    pub hide_packages: Vec<PackageManager>,
+
+    /// Only show sensors of these hwmon chips, e.g. coretemp,nvme. All chips are shown by default
+    #[arg(long = "sensor-chips", value_delimiter = ',')]
+    pub sensor_chips: Vec<String>,
+
+    /// Show pseudo filesystems, read-only images and every mount of the same device in the disk section
+    #[arg(long = "all-disks")]
+    pub all_disks: bool,
+
+    /// Only show disks mounted at these mount point globs, separated by commas, e.g. /,/home*
+    #[arg(long = "disk-include", value_delimiter = ',')]
+    pub disk_include: Vec<String>,
+
+    /// Hide disks mounted at these mount point globs, separated by commas, e.g. /boot*
+    #[arg(long = "disk-exclude", value_delimiter = ',')]
+    pub disk_exclude: Vec<String>,
+
+    /// Only show disks with these filesystem types, separated by commas, e.g. ext4,btrfs
+    #[arg(long = "disk-types", value_delimiter = ',')]
+    pub disk_types: Vec<String>,
+
+    /// Hide disks with these filesystem types, separated by commas, e.g. vfat
+    #[arg(long = "hide-disk-types", value_delimiter = ',')]
+    pub hide_disk_types: Vec<String>,
+
+    /// Hide disks smaller than this size, in GB
+    #[arg(long = "min-disk-size")]
+    pub min_disk_size: Option<f64>,
+
+    /// Show serial numbers and UUIDs of the machine and its hardware
+    #[arg(long = "show-serials")]
+    pub show_serials: bool,
+
+    /// Run a check instead of showing information, exiting non-zero when it fails
+    #[arg(long = "check", value_enum)]
+    pub check: Option<Check>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
@@ -14,4 +66,29 @@ pub enum DisplayStyle {
     Default,
     Minimal,
     Detailed,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum Check {
+    /// Fail when the kernel reports the CPU as vulnerable to any known issue
+    Vulnerabilities,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum PackageManager {
+    Dpkg,
+    Rpm,
+    Pacman,
+    Apk,
+    Flatpak,
+    Snap,
+    Nix,
+    Brew,
+    Cargo,
 }
\ No newline at end of file
@@ -0,0 +1,49 @@
+use std::path::Path;
+use colored::Colorize;
+use serde_json::json;
+use crate::cli::args::{Check, Cli, OutputFormat};
+use crate::system::cpu::VulnerabilityStatus;
+use crate::system_info::cpuinfo::{read_vulnerabilities, VULNERABILITIES_PATH};
+
+/// Run a `--check` report and return the process exit code: 0 when the check passes, 1 when it
+/// fails and 2 when the information isn't available.
+pub fn run(check: Check, cli: &Cli) -> i32 {
+    match check {
+        Check::Vulnerabilities => check_vulnerabilities(cli),
+    }
+}
+
+fn check_vulnerabilities(cli: &Cli) -> i32 {
+    let vulnerabilities = read_vulnerabilities(Path::new(VULNERABILITIES_PATH));
+    let vulnerable = vulnerabilities.iter().filter(|vulnerability| vulnerability.status == VulnerabilityStatus::Vulnerable).count();
+    let code = match (vulnerabilities.is_empty(), vulnerable) {
+        (true, _) => 2,
+        (false, 0) => 0,
+        (false, _) => 1,
+    };
+
+    if cli.format == OutputFormat::Json {
+        let report = json!({
+            "check": "vulnerabilities",
+            "passed": code == 0,
+            "vulnerabilities": vulnerabilities,
+        });
+        println!("{}", serde_json::to_string_pretty(&report).unwrap_or_default());
+        return code;
+    }
+
+    if vulnerabilities.is_empty() {
+        eprintln!("{}: {} is not readable", "ERROR".red(), VULNERABILITIES_PATH);
+        return code;
+    }
+
+    for vulnerability in &vulnerabilities {
+        println!("{}: {} ({})", vulnerability.name.yellow(), vulnerability.colored_status(), vulnerability.detail);
+    }
+    match vulnerable {
+        0 => println!("\n{}", "No vulnerable entry".green().bold()),
+        vulnerable => println!("\n{}", format!("{} vulnerable entries", vulnerable).red().bold()),
+    }
+
+    code
+}
@@ -1 +1,2 @@
-pub mod args;
\ No newline at end of file
+pub mod args;
+pub mod check;
\ No newline at end of file
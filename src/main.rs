@@ -1,8 +1,11 @@
+use std::process;
 use sysinfo::System;
 use clap::Parser;
 use colored::Colorize;
-use crate::cli::args::Cli;
+use crate::cli::args::{Cli, OutputFormat};
+use crate::cli::check;
 use crate::system_info::grabber::{ComponentType, grab};
+#[cfg(target_os = "windows")]
 use crate::utils::wmic_check::install_wmic_if_not_exist;
 
 mod cli;
@@ -11,12 +14,17 @@ mod system_info;
 mod utils;
 
 fn main() {
+    #[cfg(target_os = "windows")]
     match install_wmic_if_not_exist() {
         Ok(_) => {},
         Err(e) => { eprintln!("{}: {}","ERROR".red(), e); }
     }
 
     let cli = Cli::parse();
+    if let Some(check) = cli.check {
+        process::exit(check::run(check, &cli));
+    }
+
     let mut sys = System::new_all();
     let query_component_types = vec!(
         ComponentType::Cpu,
@@ -24,17 +32,35 @@ fn main() {
         ComponentType::Disk,
         ComponentType::Memory,
         ComponentType::OS,
+        ComponentType::Battery,
+        ComponentType::Network,
+        ComponentType::Uptime,
+        ComponentType::Session,
+        ComponentType::Terminal,
+        ComponentType::Packages,
+        ComponentType::Sensors,
+        ComponentType::MemoryModules,
+        ComponentType::Board,
+        ComponentType::Security,
+        ComponentType::Numa,
+        ComponentType::Storage,
     );
 
     let mut info_list = Vec::new();
     for query_component_type in &query_component_types {
-        let sub_info_list_result = grab(&mut sys, query_component_type);
+        let sub_info_list_result = grab(&mut sys, &cli, query_component_type);
         match sub_info_list_result {
             Ok(sub_info_list) => { info_list.extend(sub_info_list) },
             Err(_wmi_error) => { eprintln!("{}: {}","ERROR".red(), _wmi_error); }
         }
     }
 
+    if cli.format == OutputFormat::Json {
+        let info_list = info_list.iter().map(|info| info.get_json()).collect::<Vec<_>>();
+        println!("{}", serde_json::to_string_pretty(&info_list).unwrap_or_default());
+        return;
+    }
+
     // Show information
     println!("{} \n", "Information:".green().bold());
     for info in info_list {
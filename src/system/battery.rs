@@ -0,0 +1,119 @@
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+use crate::cli::args::DisplayStyle;
+use crate::system::component::{to_json, Component};
+
+#[derive(Debug, Default, Serialize)]
+pub struct BatteryInfo {
+    pub name: String,                   // Power supply name, e.g. BAT0
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub capacity: Option<u8>,           // Charge percentage
+    pub status: Option<String>,         // Charging, Discharging, Full...
+    pub time_remaining: Option<u64>,    // Second, to empty when discharging and to full when charging
+    pub cycle_count: Option<u32>,
+    pub full_capacity: Option<u64>,     // µWh or µAh, same unit as design_capacity
+    pub design_capacity: Option<u64>,   // µWh or µAh
+    pub ac_online: Option<bool>,        // Whether AC power is connected
+}
+
+impl BatteryInfo {
+    /// Full-charge capacity compared to design capacity, in percent.
+    pub fn health(&self) -> Option<f64> {
+        match (self.full_capacity, self.design_capacity) {
+            (Some(full), Some(design)) if design > 0 => Some(full as f64 / design as f64 * 100f64),
+            _ => None,
+        }
+    }
+}
+
+fn format_duration(seconds: u64) -> String {
+    let (hours, minutes) = (seconds / 3600, seconds % 3600 / 60);
+
+    if hours == 0 {
+        return format!("{}m", minutes);
+    }
+
+    format!("{}h {}m", hours, minutes)
+}
+
+impl Component for BatteryInfo {
+    fn get_info(&self, style: DisplayStyle) -> String {
+        let info = format!("{}:", "Battery".blue());
+
+        // Write battery model and charge percentage
+        let info = format!("{} {}", info, self.model.as_deref().unwrap_or(&self.name));
+        let info = match self.capacity {
+            Some(capacity) => format!("{} {}%", info, capacity),
+            None => info,
+        };
+
+        if style == DisplayStyle::Minimal {
+            return info;
+        }
+
+        // Write charging state, time remaining and power source
+        let info = format!("{}\n\t- {}: {}", info, "Status".yellow(), self.status.as_deref().unwrap_or("Unknown"));
+        let info = match self.time_remaining {
+            Some(seconds) => format!("{}\n\t- {}: {}", info, "Time remaining".yellow(), format_duration(seconds)),
+            None => info,
+        };
+        let info = match self.ac_online {
+            Some(online) => format!("{}\n\t- {}: {}", info, "AC power".yellow(), if online { "Connected" } else { "Disconnected" }),
+            None => info,
+        };
+
+        if style == DisplayStyle::Default {
+            return info;
+        }
+
+        // Write manufacturer, cycle count and health
+        let info = match &self.manufacturer {
+            Some(manufacturer) => format!("{}\n\t- {}: {}", info, "Manufacturer".yellow(), manufacturer),
+            None => info,
+        };
+        let info = match self.cycle_count {
+            Some(cycle_count) => format!("{}\n\t- {}: {}", info, "Cycle count".yellow(), cycle_count),
+            None => info,
+        };
+        let info = match self.health() {
+            Some(health) => format!("{}\n\t- {}: {:.1}%", info, "Health".yellow(), health),
+            None => info,
+        };
+
+        info
+    }
+
+    fn get_json(&self) -> Value {
+        to_json("battery", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::args::DisplayStyle;
+    use crate::system::battery::{format_duration, BatteryInfo};
+    use crate::system::component::Component;
+
+    #[test]
+    fn it_works() {
+        let battery = BatteryInfo {
+            name: "BAT0".into(),
+            model: Some("5B10W13930".into()),
+            capacity: Some(87),
+            status: Some("Discharging".into()),
+            time_remaining: Some(9000),
+            full_capacity: Some(45_000_000),
+            design_capacity: Some(50_000_000),
+            ac_online: Some(false),
+            ..Default::default()
+        };
+
+        assert_eq!(battery.health(), Some(90f64));
+        assert_eq!(format_duration(9000), "2h 30m");
+        assert_eq!(format_duration(300), "5m");
+
+        println!("{}", battery.get_info(DisplayStyle::Detailed));
+    }
+}
@@ -0,0 +1,156 @@
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+use crate::cli::args::DisplayStyle;
+use crate::system::component::{to_json, Component};
+
+#[derive(Debug, Default, Serialize)]
+pub struct BoardInfo {
+    pub system_vendor: Option<String>,
+    pub product_name: Option<String>,
+    pub product_version: Option<String>,
+    pub board_vendor: Option<String>,
+    pub board_name: Option<String>,
+    pub board_version: Option<String>,
+    pub bios_vendor: Option<String>,
+    pub bios_version: Option<String>,
+    pub bios_date: Option<String>,
+    pub bios_release: Option<String>,
+    pub chassis_type: Option<String>,       // SMBIOS chassis type, e.g. Notebook, Rack Mount Chassis
+    pub chassis: Option<String>,            // Laptop, Desktop, Server or Virtual machine
+    pub product_serial: Option<String>,     // Only filled with --show-serials
+    pub product_uuid: Option<String>,
+    pub board_serial: Option<String>,
+    pub chassis_serial: Option<String>,
+    pub cloud: Option<CloudInstance>,
+}
+
+/// Cloud provider and instance type, identified from DMI without contacting metadata services.
+#[derive(Debug, Default, Serialize)]
+pub struct CloudInstance {
+    pub provider: String,                   // AWS, GCP, Azure, Oracle Cloud, DigitalOcean or Hetzner
+    pub instance_type: Option<String>,      // e.g. m5.large
+    pub instance_family: Option<String>,    // e.g. m5
+}
+
+/// Join the present parts with spaces, e.g. vendor and product name.
+fn join(parts: &[&Option<String>]) -> Option<String> {
+    let parts = parts.iter().filter_map(|part| part.as_deref()).collect::<Vec<_>>();
+
+    match parts.is_empty() {
+        true => None,
+        false => Some(parts.join(" ")),
+    }
+}
+
+impl Component for BoardInfo {
+    fn get_info(&self, style: DisplayStyle) -> String {
+        let info = format!("{}:", "Board".blue());
+
+        // Write system model, falling back to the motherboard on self-built machines
+        let model = join(&[&self.system_vendor, &self.product_name])
+            .or_else(|| join(&[&self.board_vendor, &self.board_name]))
+            .unwrap_or_else(|| "Unknown".to_string());
+        let info = format!("{} {}", info, model);
+
+        if style == DisplayStyle::Minimal {
+            return info;
+        }
+
+        // Write motherboard, BIOS and chassis
+        let info = match join(&[&self.board_vendor, &self.board_name, &self.board_version]) {
+            Some(board) => format!("{}\n\t- {}: {}", info, "Motherboard".yellow(), board),
+            None => info,
+        };
+        let info = match join(&[&self.bios_vendor, &self.bios_version]) {
+            Some(bios) => match &self.bios_date {
+                Some(date) => format!("{}\n\t- {}: {} ({})", info, "BIOS".yellow(), bios, date),
+                None => format!("{}\n\t- {}: {}", info, "BIOS".yellow(), bios),
+            },
+            None => info,
+        };
+        let info = match &self.chassis {
+            Some(chassis) => format!("{}\n\t- {}: {}", info, "Chassis".yellow(), chassis),
+            None => info,
+        };
+        // Write cloud provider, e.g. "AWS m5.large (m5 family)"
+        let info = match &self.cloud {
+            Some(cloud) => {
+                let instance = match (&cloud.instance_type, &cloud.instance_family) {
+                    (Some(instance_type), Some(family)) => format!(" {} ({} family)", instance_type, family),
+                    (Some(instance_type), None) => format!(" {}", instance_type),
+                    (None, _) => String::new(),
+                };
+                format!("{}\n\t- {}: {}{}", info, "Cloud".yellow(), cloud.provider, instance)
+            }
+            None => info,
+        };
+
+        if style == DisplayStyle::Default {
+            return info;
+        }
+
+        // Write versions and, when requested, serial numbers
+        [
+            ("Product version", &self.product_version),
+            ("BIOS release", &self.bios_release),
+            ("Chassis type", &self.chassis_type),
+            ("Product serial", &self.product_serial),
+            ("Product UUID", &self.product_uuid),
+            ("Board serial", &self.board_serial),
+            ("Chassis serial", &self.chassis_serial),
+        ].iter().fold(info, |info, (label, value)| match value {
+            Some(value) => format!("{}\n\t- {}: {}", info, label.yellow(), value),
+            None => info,
+        })
+    }
+
+    fn get_json(&self) -> Value {
+        to_json("board", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::args::DisplayStyle;
+    use crate::system::board::{BoardInfo, CloudInstance};
+    use crate::system::component::Component;
+
+    #[test]
+    fn it_works() {
+        let board = BoardInfo {
+            system_vendor: Some("LENOVO".into()),
+            product_name: Some("21HM".into()),
+            board_vendor: Some("LENOVO".into()),
+            board_name: Some("21HMCTO1WW".into()),
+            bios_vendor: Some("LENOVO".into()),
+            bios_version: Some("N3XET53W (1.28 )".into()),
+            bios_date: Some("08/21/2024".into()),
+            chassis_type: Some("Notebook".into()),
+            chassis: Some("Laptop".into()),
+            ..Default::default()
+        };
+
+        assert!(board.get_info(DisplayStyle::Minimal).ends_with("LENOVO 21HM"));
+        assert!(board.get_info(DisplayStyle::Default).contains("N3XET53W (1.28 ) (08/21/2024)"));
+        assert!(!board.get_info(DisplayStyle::Detailed).contains("serial"));
+
+        println!("{}", board.get_info(DisplayStyle::Detailed));
+    }
+
+    #[test]
+    fn shows_cloud_instance() {
+        let board = BoardInfo {
+            system_vendor: Some("Amazon EC2".into()),
+            product_name: Some("m5.large".into()),
+            cloud: Some(CloudInstance {
+                provider: "AWS".into(),
+                instance_type: Some("m5.large".into()),
+                instance_family: Some("m5".into()),
+            }),
+            ..Default::default()
+        };
+
+        assert!(board.get_info(DisplayStyle::Default).contains("AWS m5.large (m5 family)"));
+    }
+}
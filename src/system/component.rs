@@ -1,5 +1,21 @@
+use serde::Serialize;
+use serde_json::Value;
 use crate::cli::args::DisplayStyle;
 
 pub trait Component {
     fn get_info(&self, style: DisplayStyle) -> String;
-}
\ No newline at end of file
+
+    /// Machine-readable form of the component, used by `--format json`.
+    fn get_json(&self) -> Value;
+}
+
+/// Serialize a component and tag it with its kind, e.g. `{"component": "cpu", ...}`.
+pub fn to_json<T: Serialize>(kind: &str, info: &T) -> Value {
+    let mut value = serde_json::to_value(info).unwrap_or(Value::Null);
+
+    if let Value::Object(map) = &mut value {
+        map.insert("component".into(), Value::String(kind.into()));
+    }
+
+    value
+}
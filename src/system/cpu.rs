@@ -1,13 +1,148 @@
-use colored::Colorize;
+use colored::{ColoredString, Colorize};
+use serde::Serialize;
+use serde_json::Value;
 use crate::cli::args::DisplayStyle;
-use crate::system::component::Component;
+use crate::system::component::{to_json, Component};
+use crate::utils::sysfs::format_cpu_list;
 
-#[derive(Debug)]
+#[derive(Debug, Default, Serialize)]
 pub struct CpuInfo {
     name: String,
     cores: usize,
     usage: f32,
     frequency: u64,
+    pub vendor: Option<String>,         // e.g. GenuineIntel, AuthenticAMD, ARM
+    pub family: Option<u32>,
+    pub model: Option<u32>,
+    pub stepping: Option<u32>,
+    pub microcode: Option<String>,      // Microcode revision, e.g. 0x2b000590
+    pub caches: Vec<CpuCache>,
+    pub extensions: Vec<String>,        // Instruction set extensions, e.g. AVX2, AVX-512F, SHA
+    pub x86_64_level: Option<u8>,       // x86-64 microarchitecture level, 1 to 4
+    pub core_clusters: Vec<CoreCluster>,    // Core types of hybrid CPUs, empty otherwise
+    pub frequency_policies: Vec<FrequencyPolicy>,
+    pub platform_profile: Option<String>,   // ACPI platform profile, e.g. low-power, balanced, performance
+    pub vulnerabilities: Vec<Vulnerability>,
+    pub cpu_limit: Option<f64>,         // CPUs usable under the cgroup quota and cpuset, when below the host count
+    pub cpuset: Option<Vec<usize>>,     // CPUs the cgroup may run on, when restricted
+}
+
+#[derive(Debug, Serialize)]
+pub struct CpuCache {
+    pub level: u8,
+    pub kind: String,                   // Data, Instruction or Unified
+    pub size: u64,                      // Byte
+}
+
+impl CpuCache {
+    /// Conventional name, e.g. L1d, L1i, L2.
+    pub fn name(&self) -> String {
+        match self.kind.as_str() {
+            "Data" => format!("L{}d", self.level),
+            "Instruction" => format!("L{}i", self.level),
+            _ => format!("L{}", self.level),
+        }
+    }
+}
+
+/// CPUs of the same core type, e.g. the P-cores of an Intel hybrid CPU or a big.LITTLE cluster.
+#[derive(Debug, Default, Serialize)]
+pub struct CoreCluster {
+    pub kind: String,                   // P for performance cores, E for efficiency cores, LP-E for low power efficiency cores
+    pub name: Option<String>,           // Core microarchitecture when known, e.g. Cortex-A710
+    pub cores: usize,
+    pub cpus: Vec<usize>,               // Logical CPUs, i.e. threads
+    pub max_frequency: Option<u64>,     // MHz
+}
+
+/// A cpufreq policy, i.e. CPUs scaled together.
+#[derive(Debug, Default, Serialize)]
+pub struct FrequencyPolicy {
+    pub cpus: Vec<usize>,
+    pub driver: Option<String>,                         // e.g. intel_pstate, amd-pstate-epp, acpi-cpufreq
+    pub governor: Option<String>,                       // e.g. powersave, performance, schedutil
+    pub min_frequency: Option<u64>,                     // MHz, scaling limits
+    pub max_frequency: Option<u64>,                     // MHz
+    pub hardware_max_frequency: Option<u64>,            // MHz
+    pub base_frequency: Option<u64>,                    // MHz
+    pub energy_performance_preference: Option<String>,  // e.g. balance_performance
+    pub boost: Option<bool>,                            // Turbo Boost or Core Performance Boost
+}
+
+impl FrequencyPolicy {
+    /// One line description of the settings, e.g.
+    /// "intel_pstate powersave, 800-5000MHz (base 2100MHz), EPP balance_performance, boost on".
+    fn summary(&self) -> String {
+        let mut fields = Vec::new();
+
+        let scaling = [&self.driver, &self.governor].iter().filter_map(|value| value.as_deref()).collect::<Vec<_>>();
+        if !scaling.is_empty() {
+            fields.push(scaling.join(" "));
+        }
+        if let (Some(min), Some(max)) = (self.min_frequency, self.max_frequency) {
+            fields.push(match self.base_frequency {
+                Some(base) => format!("{}-{}MHz (base {}MHz)", min, max, base),
+                None => format!("{}-{}MHz", min, max),
+            });
+        }
+        if let Some(preference) = &self.energy_performance_preference {
+            fields.push(format!("EPP {}", preference));
+        }
+        if let Some(boost) = self.boost {
+            fields.push(format!("boost {}", if boost { "on" } else { "off" }));
+        }
+
+        fields.join(", ")
+    }
+}
+
+fn format_cache_size(bytes: u64) -> String {
+    match bytes % (1024 * 1024) {
+        0 => format!("{}MB", bytes / 1024 / 1024),
+        _ if bytes >= 1024 * 1024 => format!("{:.2}MB", bytes as f64 / 1024f64.powi(2)),
+        _ => format!("{}KB", bytes / 1024),
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VulnerabilityStatus {
+    NotAffected,
+    Mitigated,
+    Vulnerable,
+    Unknown,
+}
+
+/// An entry of /sys/devices/system/cpu/vulnerabilities, e.g. spectre_v2.
+#[derive(Debug, Serialize)]
+pub struct Vulnerability {
+    pub name: String,
+    pub status: VulnerabilityStatus,
+    pub detail: String,     // Kernel text, e.g. "Mitigation: Enhanced / Automatic IBRS; IBPB: conditional"
+}
+
+impl Vulnerability {
+    /// Classify by the leading word of the kernel text, as the kernel documents it:
+    /// "Not affected", "Vulnerable[: ...]" or "Mitigation: ...". itlb_multihit prefixes it with "KVM: ".
+    pub fn new(name: String, detail: String) -> Vulnerability {
+        let status = match detail.strip_prefix("KVM: ").unwrap_or(&detail) {
+            "Not affected" => VulnerabilityStatus::NotAffected,
+            detail if detail.starts_with("Vulnerable") => VulnerabilityStatus::Vulnerable,
+            detail if detail.starts_with("Mitigation") => VulnerabilityStatus::Mitigated,
+            _ => VulnerabilityStatus::Unknown,
+        };
+
+        Vulnerability { name, status, detail }
+    }
+
+    pub fn colored_status(&self) -> ColoredString {
+        match self.status {
+            VulnerabilityStatus::NotAffected => "not affected".green(),
+            VulnerabilityStatus::Mitigated => "mitigated".yellow(),
+            VulnerabilityStatus::Vulnerable => "vulnerable".red(),
+            VulnerabilityStatus::Unknown => "unknown".normal(),
+        }
+    }
 }
 
 impl CpuInfo {
@@ -22,6 +157,7 @@ impl CpuInfo {
             cores,
             usage,
             frequency,
+            ..Default::default()
         }
     }
 }
@@ -37,8 +173,24 @@ impl Component for CpuInfo {
             return info;
         }
 
-        // Write CPU cores
-        info = format!("{}\n\t- {}: {}", info, "Cores".yellow(), self.cores);
+        // Write CPU cores, e.g. "6P + 8E (20 threads)" on hybrid CPUs
+        let cores = match self.core_clusters.is_empty() {
+            true => self.cores.to_string(),
+            false => {
+                let clusters = self.core_clusters.iter()
+                    .map(|cluster| match &cluster.name {
+                        Some(name) => format!("{} {}", cluster.cores, name),
+                        None => format!("{}{}", cluster.cores, cluster.kind),
+                    })
+                    .collect::<Vec<_>>();
+                format!("{} ({} threads)", clusters.join(" + "), self.cores)
+            }
+        };
+        // Inside a limited cgroup, e.g. "1.5 (limit; host 20)"
+        info = match self.cpu_limit {
+            Some(limit) => format!("{}\n\t- {}: {} (limit; host {})", info, "Cores".yellow(), (limit * 100f64).round() / 100f64, cores),
+            None => format!("{}\n\t- {}: {}", info, "Cores".yellow(), cores),
+        };
 
         if style == DisplayStyle::Default {
             return info;
@@ -48,9 +200,85 @@ impl Component for CpuInfo {
         info = format!("{}\n\t- {}: {:.1}%", info, "Usage".yellow(), self.usage);
         // Write CPU frequency
         info = format!("{}\n\t- {}: {}MHz", info, "Frequency".yellow(), self.frequency);
+        // Write the CPUs the cgroup may run on
+        if let Some(cpuset) = &self.cpuset {
+            info = format!("{}\n\t- {}: {}", info, "CPU set".yellow(), format_cpu_list(cpuset));
+        }
+
+        // Write each core type of hybrid CPUs
+        for cluster in &self.core_clusters {
+            let label = format!("{}-cores", cluster.kind);
+            let mut fields = vec![format!("{} cores, {} threads, CPU {}", cluster.cores, cluster.cpus.len(), format_cpu_list(&cluster.cpus))];
+            fields.extend(cluster.name.clone());
+            fields.extend(cluster.max_frequency.map(|frequency| format!("up to {}MHz", frequency)));
+            info = format!("{}\n\t- {}: {}", info, label.yellow(), fields.join(", "));
+        }
+
+        // Write identification, caches and instruction set extensions
+        if let Some(vendor) = &self.vendor {
+            info = format!("{}\n\t- {}: {}", info, "Vendor".yellow(), vendor);
+        }
+        if let (Some(family), Some(model), Some(stepping)) = (self.family, self.model, self.stepping) {
+            info = format!("{}\n\t- {}: family {}, model {}, stepping {} ({:X}h/{:X}h/{:X}h)", info, "Signature".yellow(),
+                family, model, stepping, family, model, stepping,
+            );
+        }
+        if let Some(microcode) = &self.microcode {
+            info = format!("{}\n\t- {}: {}", info, "Microcode".yellow(), microcode);
+        }
+        if !self.caches.is_empty() {
+            let caches = self.caches.iter().map(|cache| format!("{} {}", cache.name(), format_cache_size(cache.size))).collect::<Vec<_>>();
+            info = format!("{}\n\t- {}: {}", info, "Caches".yellow(), caches.join(", "));
+        }
+        if !self.extensions.is_empty() {
+            info = format!("{}\n\t- {}: {}", info, "Extensions".yellow(), self.extensions.join(" "));
+        }
+        if let Some(level) = self.x86_64_level {
+            info = format!("{}\n\t- {}: x86-64-v{}", info, "Microarchitecture level".yellow(), level);
+        }
+
+        // Write frequency scaling, a single line when every policy has the same settings
+        let mut groups: Vec<(Vec<usize>, String)> = Vec::new();
+        for policy in &self.frequency_policies {
+            let summary = policy.summary();
+            match groups.iter_mut().find(|(_, group)| *group == summary) {
+                Some((cpus, _)) => cpus.extend(&policy.cpus),
+                None => groups.push((policy.cpus.clone(), summary)),
+            }
+        }
+        match groups.as_slice() {
+            [] => {}
+            [(_, summary)] => info = format!("{}\n\t- {}: {}", info, "Scaling".yellow(), summary),
+            groups => {
+                info = format!("{}\n\t- {}:", info, "Scaling".yellow());
+                for (cpus, summary) in groups {
+                    let mut cpus = cpus.clone();
+                    cpus.sort();
+                    info = format!("{}\n\t\t- CPU {}: {}", info, format_cpu_list(&cpus), summary);
+                }
+            }
+        }
+        if let Some(profile) = &self.platform_profile {
+            info = format!("{}\n\t- {}: {}", info, "Platform profile".yellow(), profile);
+        }
+
+        // Write vulnerability summary, then each entry the CPU is affected by
+        if !self.vulnerabilities.is_empty() {
+            let count = |status: VulnerabilityStatus| self.vulnerabilities.iter().filter(|vulnerability| vulnerability.status == status).count();
+            info = format!("{}\n\t- {}: {} vulnerable, {} mitigated, {} not affected", info, "Vulnerabilities".yellow(),
+                count(VulnerabilityStatus::Vulnerable), count(VulnerabilityStatus::Mitigated), count(VulnerabilityStatus::NotAffected),
+            );
+        }
+        for vulnerability in self.vulnerabilities.iter().filter(|vulnerability| vulnerability.status != VulnerabilityStatus::NotAffected) {
+            info = format!("{}\n\t- {}: {}", info, vulnerability.name.yellow(), vulnerability.colored_status());
+        }
 
         info
     }
+
+    fn get_json(&self) -> Value {
+        to_json("cpu", self)
+    }
 }
 
 #[cfg(test)]
@@ -58,7 +286,7 @@ mod tests {
     use sysinfo::System;
     use crate::cli::args::DisplayStyle;
     use crate::system::component::Component;
-    use crate::system::cpu::CpuInfo;
+    use crate::system::cpu::{CoreCluster, CpuCache, CpuInfo, FrequencyPolicy, Vulnerability, VulnerabilityStatus};
 
     #[test]
     fn it_works() {
@@ -77,6 +305,86 @@ mod tests {
         println!("{}", cpu.get_info(DisplayStyle::Detailed));
     }
 
+    #[test]
+    fn shows_cgroup_limit() {
+        let mut cpu = CpuInfo::new("AMD EPYC 7763 64-Core Processor".into(), 128, 12.0, 2450);
+        cpu.cpu_limit = Some(1.5);
+        cpu.cpuset = Some(vec![0, 1, 2, 3]);
+
+        let info = cpu.get_info(DisplayStyle::Detailed);
+        assert!(info.contains("1.5 (limit; host 128)"));
+        assert!(info.contains("0-3"));
+    }
+
+    #[test]
+    fn shows_identification() {
+        let mut cpu = CpuInfo::new("Intel(R) Xeon(R) Platinum 8480+".into(), 112, 3.5, 2000);
+        cpu.family = Some(6);
+        cpu.model = Some(143);
+        cpu.stepping = Some(8);
+        cpu.caches = vec![
+            CpuCache { level: 1, kind: "Data".into(), size: 48 * 1024 },
+            CpuCache { level: 2, kind: "Unified".into(), size: 2 * 1024 * 1024 },
+            CpuCache { level: 3, kind: "Unified".into(), size: 105 * 1024 * 1024 },
+        ];
+        cpu.extensions = vec!["AVX2".into(), "AVX-512F".into()];
+        cpu.x86_64_level = Some(4);
+
+        let info = cpu.get_info(DisplayStyle::Detailed);
+        assert!(info.contains("family 6, model 143, stepping 8 (6h/8Fh/8h)"));
+        assert!(info.contains("L1d 48KB, L2 2MB, L3 105MB"));
+        assert!(info.contains("x86-64-v4"));
+    }
+
+    #[test]
+    fn shows_core_types() {
+        let mut cpu = CpuInfo::new("13th Gen Intel(R) Core(TM) i7-13700H".into(), 20, 1.0, 800);
+        cpu.core_clusters = vec![
+            CoreCluster { kind: "P".into(), cores: 6, cpus: (0..12).collect(), max_frequency: Some(5000), ..Default::default() },
+            CoreCluster { kind: "E".into(), cores: 8, cpus: (12..20).collect(), max_frequency: Some(3700), ..Default::default() },
+        ];
+
+        assert!(cpu.get_info(DisplayStyle::Default).ends_with(": 6P + 8E (20 threads)"));
+        assert!(cpu.get_info(DisplayStyle::Detailed).contains("8 cores, 8 threads, CPU 12-19, up to 3700MHz"));
+    }
+
+    #[test]
+    fn shows_frequency_policies() {
+        let policy = |cpu: usize, max: u64| FrequencyPolicy {
+            cpus: vec![cpu],
+            driver: Some("intel_pstate".into()),
+            governor: Some("powersave".into()),
+            min_frequency: Some(800),
+            max_frequency: Some(max),
+            boost: Some(true),
+            ..Default::default()
+        };
+
+        let mut cpu = CpuInfo::new("Intel".into(), 4, 1.0, 800);
+        cpu.frequency_policies = vec![policy(0, 5000), policy(1, 5000)];
+        assert!(cpu.get_info(DisplayStyle::Detailed).contains(": intel_pstate powersave, 800-5000MHz, boost on"));
+
+        // Hybrid CPUs get a row per set of settings.
+        cpu.frequency_policies = vec![policy(0, 5000), policy(1, 5000), policy(2, 3800), policy(3, 3800)];
+        let info = cpu.get_info(DisplayStyle::Detailed);
+        assert!(info.contains("CPU 0-1: intel_pstate powersave, 800-5000MHz, boost on"));
+        assert!(info.contains("CPU 2-3: intel_pstate powersave, 800-3800MHz, boost on"));
+
+        println!("{}", info);
+    }
+
+    #[test]
+    fn classifies_vulnerabilities() {
+        let vulnerability = |detail: &str| Vulnerability::new("spectre_v2".into(), detail.into()).status;
+
+        assert_eq!(vulnerability("Not affected"), VulnerabilityStatus::NotAffected);
+        assert_eq!(vulnerability("Mitigation: Enhanced / Automatic IBRS; IBPB: conditional"), VulnerabilityStatus::Mitigated);
+        assert_eq!(vulnerability("Vulnerable: Clear CPU buffers attempted, no microcode; SMT vulnerable"), VulnerabilityStatus::Vulnerable);
+        assert_eq!(vulnerability("Vulnerable"), VulnerabilityStatus::Vulnerable);
+        assert_eq!(vulnerability("KVM: Mitigation: VMX disabled"), VulnerabilityStatus::Mitigated);
+        assert_eq!(vulnerability("Unknown: Dependent on hypervisor status"), VulnerabilityStatus::Unknown);
+    }
+
     #[test]
     fn get_cpu_info() {
         let mut sys = System::new_all();
@@ -1,24 +1,50 @@
 use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
 use crate::cli::args::DisplayStyle;
-use crate::system::component::Component;
+use crate::system::component::{to_json, Component};
+use crate::utils::format::format_rate;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct DiskInfo {
     name: String,
     file_system: String,
     total_space: u64,       // Byte
     available_space: u64,   // Byte
     disk_type: String,
+    throughput: Option<DiskThroughput>,
+    pub mount_points: Vec<String>,  // Where the filesystem is mounted, bind mounts and subvolumes included
+    pub source: Option<String>,     // Device or remote the filesystem is mounted from, e.g. /dev/mapper/vg0-root
+    pub uuid: Option<String>,       // Filesystem UUID
+    pub label: Option<String>,      // Filesystem label
+    pub mount_options: Vec<String>, // e.g. rw, noatime, compress=zstd:3
+    pub inodes_total: Option<u64>,  // None for filesystems allocating inodes dynamically, e.g. btrfs
+    pub inodes_free: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiskThroughput {
+    pub read_per_second: f64,       // Byte
+    pub written_per_second: f64,    // Byte
+    pub iops: f64,                  // Completed reads and writes per second
 }
 
 impl DiskInfo {
-    pub fn new(name: String, file_system: String, total_space: u64, available_space: u64, disk_type: String) -> DiskInfo {
+    pub fn new(name: String, file_system: String, total_space: u64, available_space: u64, disk_type: String, throughput: Option<DiskThroughput>) -> DiskInfo {
         DiskInfo {
             name,
             file_system,
             total_space,
             available_space,
             disk_type,
+            throughput,
+            mount_points: Vec::new(),
+            source: None,
+            uuid: None,
+            label: None,
+            mount_options: Vec::new(),
+            inodes_total: None,
+            inodes_free: None,
         }
     }
 }
@@ -34,7 +60,11 @@ impl Component for DiskInfo {
             return info;
         }
 
-        // Write disk total space and available space
+        // Write mount points and disk total space and available space
+        let info = match self.mount_points.is_empty() {
+            true => info,
+            false => format!("{}\n\t- {}: {}", info, "Mount points".yellow(), self.mount_points.join(", ")),
+        };
         let info = format!("{}\n\t- {}: {:.2}GB", info, "Total space".yellow(), self.total_space as f64 / 1024f64.powi(3));
         let info = format!("{}\n\t- {}: {:.2}GB", info, "Available space".yellow(), self.available_space as f64 / 1024f64.powi(3));
         let info = format!("{}\n\t- {}: {:.2}%", info, "Occupancy".yellow(), 100f64 - self.available_space as f64 / self.total_space as f64 * 100f64);
@@ -47,14 +77,47 @@ impl Component for DiskInfo {
         let info = format!("{}\n\t- {}: {}", info, "Disk type".yellow(), self.disk_type);
         let info = format!("{}\n\t- {}: {}", info, "File system".yellow(), self.file_system);
 
+        // Write source device, UUID, label, mount options and inode use
+        let info = [("Source", &self.source), ("UUID", &self.uuid), ("Label", &self.label)].iter()
+            .fold(info, |info, (label, value)| match value {
+                Some(value) => format!("{}\n\t- {}: {}", info, label.yellow(), value),
+                None => info,
+            });
+        let info = match self.mount_options.is_empty() {
+            true => info,
+            false => format!("{}\n\t- {}: {}", info, "Mount options".yellow(), self.mount_options.join(", ")),
+        };
+        let info = match (self.inodes_total, self.inodes_free) {
+            (Some(total), Some(free)) => format!("{}\n\t- {}: {}/{} used ({:.2}%)", info, "Inodes".yellow(),
+                total - free, total, (total - free) as f64 / total as f64 * 100f64,
+            ),
+            _ => info,
+        };
+
+        // Write read and write rates
+        let info = match &self.throughput {
+            Some(throughput) => {
+                let info = format!("{}\n\t- {}: {}", info, "Read rate".yellow(), format_rate(throughput.read_per_second));
+                let info = format!("{}\n\t- {}: {}", info, "Write rate".yellow(), format_rate(throughput.written_per_second));
+                format!("{}\n\t- {}: {:.0}", info, "IOPS".yellow(), throughput.iops)
+            }
+            None => info,
+        };
+
         info
     }
+
+    fn get_json(&self) -> Value {
+        to_json("disk", self)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use sysinfo::{Disks, System};
-    use crate::system::disk::DiskInfo;
+    use crate::cli::args::DisplayStyle;
+    use crate::system::component::Component;
+    use crate::system::disk::{DiskInfo, DiskThroughput};
 
     #[test]
     fn it_works() {
@@ -64,15 +127,43 @@ mod tests {
             8196u64.pow(3),
             2048u64.pow(3),
             "SSD".to_string(),
+            Some(DiskThroughput {
+                read_per_second: 2048f64,
+                written_per_second: 0f64,
+                iops: 12f64,
+            }),
         );
 
         assert_eq!(disk_info.name, "SAMSUNG");
         assert_eq!(disk_info.total_space, 8196u64.pow(3));
         assert_eq!(disk_info.available_space, 2048u64.pow(3));
         assert_eq!(disk_info.file_system, "NTFS");
+        assert_eq!(disk_info.throughput.as_ref().unwrap().iops, 12f64);
 
         println!("{:#?}", Disks::new_with_refreshed_list().list());
 
         let sys = System::new_all();
     }
+
+    #[test]
+    fn shows_mount_details() {
+        let mut disk_info = DiskInfo::new(
+            "/dev/nvme0n1p2".to_string(),
+            "btrfs".to_string(),
+            900 * 1024u64.pow(3),
+            600 * 1024u64.pow(3),
+            "SSD".to_string(),
+            None,
+        );
+        disk_info.source = Some("/dev/nvme0n1p2".into());
+        disk_info.mount_options = vec!["rw".into(), "noatime".into(), "compress=zstd:3".into()];
+        disk_info.inodes_total = Some(6553600);
+        disk_info.inodes_free = Some(6422528);
+
+        let info = disk_info.get_info(DisplayStyle::Detailed);
+        assert!(info.contains("Source: /dev/nvme0n1p2"));
+        assert!(info.contains("rw, noatime, compress=zstd:3"));
+        assert!(info.contains("131072/6553600 used (2.00%)"));
+        assert!(!disk_info.get_info(DisplayStyle::Default).contains("Inodes"));
+    }
 }
@@ -1,8 +1,10 @@
 use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
 use crate::cli::args::DisplayStyle;
-use crate::system::component::Component;
+use crate::system::component::{to_json, Component};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct GpuInfo {
     name: String,                   // GPU name
     driver: String,                 // Driver version
@@ -51,6 +53,10 @@ impl Component for GpuInfo {
 
         info
     }
+
+    fn get_json(&self) -> Value {
+        to_json("gpu", self)
+    }
 }
 
 #[cfg(test)]
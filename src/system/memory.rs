@@ -1,10 +1,75 @@
 use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
 use crate::cli::args::DisplayStyle;
-use crate::system::component::Component;
+use crate::system::component::{to_json, Component};
 
+#[derive(Default, Serialize)]
 pub struct MemoryInfo {
     total_memory: u64,      // Byte
-    free_memory: u64,       // Byte,
+    free_memory: u64,       // Byte, memory available for new allocations
+    pub breakdown: Option<MemoryBreakdown>,
+    pub swap_total: u64,    // Byte
+    pub swap_used: u64,     // Byte
+    pub swaps: Vec<SwapDevice>,
+    pub zram: Vec<ZramDevice>,
+    pub huge_pages: Option<HugePages>,
+    pub limit: Option<u64>,         // Byte, cgroup memory limit when below the host total
+    pub limit_used: Option<u64>,    // Byte, usage charged to the cgroup
+}
+
+/// Composition of physical memory from /proc/meminfo, in bytes.
+#[derive(Debug, Default, Serialize)]
+pub struct MemoryBreakdown {
+    pub free: u64,
+    pub available: u64,
+    pub buffers: u64,
+    pub cached: u64,            // Page cache and reclaimable slab
+    pub shared: u64,
+    pub slab: u64,
+}
+
+impl MemoryBreakdown {
+    /// Memory used by processes and the kernel, i.e. neither free, buffers nor cache.
+    pub fn used(&self, total: u64) -> u64 {
+        total.saturating_sub(self.free + self.buffers + self.cached)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct SwapDevice {
+    pub name: String,           // Device or file path
+    pub kind: String,           // partition or file
+    pub size: u64,              // Byte
+    pub used: u64,              // Byte
+    pub priority: i32,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ZramDevice {
+    pub name: String,
+    pub algorithm: Option<String>,
+    pub disk_size: u64,         // Byte
+    pub original_size: u64,     // Byte, uncompressed data stored
+    pub compressed_size: u64,   // Byte
+    pub memory_used: u64,       // Byte, including allocator overhead
+}
+
+impl ZramDevice {
+    pub fn compression_ratio(&self) -> Option<f64> {
+        match self.compressed_size {
+            0 => None,
+            compressed_size => Some(self.original_size as f64 / compressed_size as f64),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct HugePages {
+    pub total: u64,                     // Preallocated huge pages
+    pub free: u64,
+    pub page_size: u64,                 // Byte
+    pub transparent: Option<String>,    // Transparent huge pages mode: always, madvise or never
 }
 
 impl MemoryInfo {
@@ -15,36 +80,137 @@ impl MemoryInfo {
         MemoryInfo {
             total_memory,
             free_memory,
+            ..Default::default()
+        }
+    }
+
+    /// A bar of `width` characters made of used, buffers, cached and free memory.
+    fn composition_bar(&self, breakdown: &MemoryBreakdown, width: usize) -> String {
+        let segments = [
+            (breakdown.used(self.total_memory), "█".green()),
+            (breakdown.buffers, "█".blue()),
+            (breakdown.cached, "█".yellow()),
+        ];
+
+        let mut bar = String::new();
+        let mut filled = 0;
+        for (size, block) in segments {
+            let length = (size as f64 / self.total_memory as f64 * width as f64).round() as usize;
+            let length = length.min(width - filled);
+            bar.push_str(&block.to_string().repeat(length));
+            filled += length;
         }
+        bar.push_str(&"░".repeat(width - filled));
+
+        format!("[{}] {} {} {} {}", bar, "used".green(), "buffers".blue(), "cached".yellow(), "free")
     }
 }
 
+fn gb(bytes: u64) -> f64 {
+    bytes as f64 / 1024f64.powi(3)
+}
+
 impl Component for MemoryInfo {
     fn get_info(&self, style: DisplayStyle) -> String {
         let info = format!("{}:", "Memory".blue());
 
         let (used, total) = (self.total_memory as f64 - self.free_memory as f64, self.total_memory as f64);
 
-        // Write Memory use circumstance
-        let info = format!("{} {:.1}GB/{:.1}GB",
-            info,
-            used / 1024f64.powi(3),
-            total / 1024f64.powi(3),
-        );
+        // Write Memory use circumstance, against the cgroup limit when there is one
+        let info = match self.limit {
+            Some(limit) => format!("{} {:.1}GB/{:.1}GB (limit; host {:.1}GB)",
+                info,
+                gb(self.limit_used.unwrap_or(0)),
+                gb(limit),
+                total / 1024f64.powi(3),
+            ),
+            None => format!("{} {:.1}GB/{:.1}GB",
+                info,
+                used / 1024f64.powi(3),
+                total / 1024f64.powi(3),
+            ),
+        };
+
+        if style == DisplayStyle::Minimal {
+            return info;
+        }
+
+        // Write swap use circumstance
+        let info = match self.swap_total {
+            0 => info,
+            swap_total => format!("{}\n\t- {}: {:.1}GB/{:.1}GB", info, "Swap".yellow(), gb(self.swap_used), gb(swap_total)),
+        };
 
-        if style == DisplayStyle::Default || style == DisplayStyle::Minimal {
+        if style == DisplayStyle::Default {
             return info;
         }
 
         let info = format!("{}\n\t- {}: {:.2}%", info, "Occupancy".yellow(), used / total * 100f64);
 
+        // Write memory composition
+        let info = match &self.breakdown {
+            Some(breakdown) if self.total_memory > 0 => {
+                let info = format!("{}\n\t- {}: {}", info, "Composition".yellow(), self.composition_bar(breakdown, 30));
+                [
+                    ("Used", breakdown.used(self.total_memory)),
+                    ("Available", breakdown.available),
+                    ("Buffers", breakdown.buffers),
+                    ("Cached", breakdown.cached),
+                    ("Shared", breakdown.shared),
+                    ("Slab", breakdown.slab),
+                ].iter().fold(info, |info, (label, bytes)| format!("{}\n\t- {}: {:.2}GB", info, label.yellow(), gb(*bytes)))
+            }
+            _ => info,
+        };
+
+        // Write swap devices and zram
+        let info = self.swaps.iter().fold(info, |info, swap| {
+            format!("{}\n\t- {}: {} ({}, priority {}) {:.2}GB/{:.2}GB", info, "Swap device".yellow(),
+                swap.name, swap.kind, swap.priority, gb(swap.used), gb(swap.size),
+            )
+        });
+        let info = self.zram.iter().fold(info, |info, zram| {
+            let info = format!("{}\n\t- {}: {} {:.2}GB", info, "zram".yellow(), zram.name, gb(zram.disk_size));
+            let info = match &zram.algorithm {
+                Some(algorithm) => format!("{}, {}", info, algorithm),
+                None => info,
+            };
+            match zram.compression_ratio() {
+                Some(ratio) => format!("{}, {:.2}GB stored in {:.2}GB (ratio {:.2})", info,
+                    gb(zram.original_size), gb(zram.compressed_size), ratio,
+                ),
+                None => info,
+            }
+        });
+
+        // Write huge page configuration
+        let info = match &self.huge_pages {
+            Some(huge_pages) => {
+                let info = format!("{}\n\t- {}: {} ({} free) of {}KB", info, "Huge pages".yellow(),
+                    huge_pages.total, huge_pages.free, huge_pages.page_size / 1024,
+                );
+                match &huge_pages.transparent {
+                    Some(transparent) => format!("{}\n\t- {}: {}", info, "Transparent huge pages".yellow(), transparent),
+                    None => info,
+                }
+            }
+            None => info,
+        };
+
         info
     }
+
+    fn get_json(&self) -> Value {
+        to_json("memory", self)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use sysinfo::System;
+    use crate::cli::args::DisplayStyle;
+    use crate::system::component::Component;
+    use crate::system::memory::{MemoryBreakdown, MemoryInfo, ZramDevice};
 
     #[test]
     fn it_works() {
@@ -52,4 +218,44 @@ mod tests {
 
         println!("{:#?}", sys.total_memory());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn detailed_breakdown() {
+        let gb = 1024u64.pow(3);
+        let mut memory = MemoryInfo::new(16 * gb, 10 * gb);
+        memory.breakdown = Some(MemoryBreakdown {
+            free: 4 * gb,
+            available: 10 * gb,
+            buffers: gb,
+            cached: 5 * gb,
+            shared: gb / 2,
+            slab: gb / 4,
+        });
+        memory.zram.push(ZramDevice {
+            name: "zram0".into(),
+            original_size: 3 * gb,
+            compressed_size: gb,
+            memory_used: 2 * gb,
+            ..Default::default()
+        });
+
+        assert_eq!(memory.breakdown.as_ref().unwrap().used(16 * gb), 6 * gb);
+        assert_eq!(memory.zram[0].compression_ratio(), Some(3f64));
+
+        let info = memory.get_info(DisplayStyle::Detailed);
+        assert!(info.contains("Cached"));
+        assert!(info.contains("3.00GB stored in 1.00GB (ratio 3.00)"));
+
+        println!("{}", info);
+    }
+
+    #[test]
+    fn shows_cgroup_limit() {
+        let gb = 1024u64.pow(3);
+        let mut memory = MemoryInfo::new(64 * gb, 40 * gb);
+        memory.limit = Some(4 * gb);
+        memory.limit_used = Some(gb + gb / 5);
+
+        assert!(memory.get_info(DisplayStyle::Minimal).ends_with("1.2GB/4.0GB (limit; host 64.0GB)"));
+    }
+}
@@ -0,0 +1,151 @@
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+use crate::cli::args::DisplayStyle;
+use crate::system::component::{to_json, Component};
+
+#[derive(Debug, Default, Serialize)]
+pub struct MemoryArray {
+    pub maximum_capacity: Option<u64>,      // Byte
+    pub slots: u16,
+    pub error_correction: Option<String>,   // e.g. None, Single-bit ECC, Multi-bit ECC
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct MemoryModule {
+    pub locator: String,                    // Slot label, e.g. DIMM_A1 or ChannelA-DIMM0
+    pub bank: Option<String>,
+    pub size: Option<u64>,                  // Byte, None for an empty slot
+    pub memory_type: Option<String>,        // DDR4, DDR5, LPDDR5...
+    pub form_factor: Option<String>,        // DIMM, SODIMM...
+    pub speed: Option<u32>,                 // MT/s, rated maximum
+    pub configured_speed: Option<u32>,      // MT/s, as currently running
+    pub manufacturer: Option<String>,
+    pub part_number: Option<String>,
+    pub rank: Option<u8>,
+    pub ecc: bool,                          // Extra data lines in use for error correction
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct MemoryModulesInfo {
+    pub arrays: Vec<MemoryArray>,
+    pub modules: Vec<MemoryModule>,
+    pub error: Option<String>,              // Why the SMBIOS tables could not be read
+}
+
+impl MemoryModulesInfo {
+    fn installed(&self) -> impl Iterator<Item = &MemoryModule> {
+        self.modules.iter().filter(|module| module.size.is_some())
+    }
+}
+
+fn format_size(bytes: u64) -> String {
+    match bytes >= 1024u64.pow(3) {
+        true => format!("{}GB", bytes / 1024u64.pow(3)),
+        false => format!("{}MB", bytes / 1024u64.pow(2)),
+    }
+}
+
+impl Component for MemoryModulesInfo {
+    fn get_info(&self, style: DisplayStyle) -> String {
+        let info = format!("{}:", "Memory modules".blue());
+
+        if let Some(error) = &self.error {
+            return format!("{} {}", info, error.red());
+        }
+
+        // Write populated slots and total size, e.g. "2/4 slots, 32GB DDR5"
+        let installed = self.installed().collect::<Vec<_>>();
+        let total = installed.iter().filter_map(|module| module.size).sum::<u64>();
+        let info = format!("{} {}/{} slots, {}", info, installed.len(), self.modules.len(), format_size(total));
+        let info = match installed.first().and_then(|module| module.memory_type.as_deref()) {
+            Some(memory_type) => format!("{} {}", info, memory_type),
+            None => info,
+        };
+
+        if style == DisplayStyle::Minimal {
+            return info;
+        }
+
+        // Write each slot, e.g. "DIMM_A1: 16GB DDR5 4800MT/s Kingston KF556C40-16"
+        let detailed = style == DisplayStyle::Detailed;
+        let info = self.modules.iter().fold(info, |info, module| {
+            let Some(size) = module.size else {
+                return format!("{}\n\t- {}: {}", info, module.locator.yellow(), "empty");
+            };
+
+            let mut fields = vec![format_size(size)];
+            fields.extend(module.memory_type.clone());
+            fields.extend(module.configured_speed.or(module.speed).map(|speed| format!("{}MT/s", speed)));
+            if detailed {
+                if let (Some(speed), Some(configured_speed)) = (module.speed, module.configured_speed) {
+                    if speed != configured_speed {
+                        fields.push(format!("(rated {}MT/s)", speed));
+                    }
+                }
+                fields.extend(module.form_factor.clone());
+                fields.extend(module.rank.map(|rank| format!("{}R", rank)));
+                if module.ecc {
+                    fields.push("ECC".to_string());
+                }
+            }
+            fields.extend(module.manufacturer.clone());
+            fields.extend(module.part_number.clone());
+
+            format!("{}\n\t- {}: {}", info, module.locator.yellow(), fields.join(" "))
+        });
+
+        if style == DisplayStyle::Default {
+            return info;
+        }
+
+        // Write memory arrays, e.g. "max 128GB, 4 slots, Single-bit ECC"
+        let info = self.arrays.iter().fold(info, |info, array| {
+            let mut fields = Vec::new();
+            fields.extend(array.maximum_capacity.map(|capacity| format!("max {}", format_size(capacity))));
+            fields.push(format!("{} slots", array.slots));
+            fields.extend(array.error_correction.clone());
+
+            format!("{}\n\t- {}: {}", info, "Array".yellow(), fields.join(", "))
+        });
+
+        info
+    }
+
+    fn get_json(&self) -> Value {
+        to_json("memory_modules", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::args::DisplayStyle;
+    use crate::system::component::Component;
+    use crate::system::memory_modules::{MemoryArray, MemoryModule, MemoryModulesInfo};
+
+    #[test]
+    fn it_works() {
+        let modules = MemoryModulesInfo {
+            arrays: vec![MemoryArray { maximum_capacity: Some(128 * 1024u64.pow(3)), slots: 2, error_correction: Some("None".into()) }],
+            modules: vec![
+                MemoryModule {
+                    locator: "DIMM_A1".into(),
+                    size: Some(16 * 1024u64.pow(3)),
+                    memory_type: Some("DDR5".into()),
+                    speed: Some(5600),
+                    configured_speed: Some(4800),
+                    manufacturer: Some("Kingston".into()),
+                    part_number: Some("KF556C40-16".into()),
+                    ..Default::default()
+                },
+                MemoryModule { locator: "DIMM_A2".into(), ..Default::default() },
+            ],
+            ..Default::default()
+        };
+
+        assert!(modules.get_info(DisplayStyle::Minimal).ends_with("1/2 slots, 16GB DDR5"));
+        assert!(modules.get_info(DisplayStyle::Detailed).contains("16GB DDR5 4800MT/s (rated 5600MT/s) Kingston KF556C40-16"));
+
+        println!("{}", modules.get_info(DisplayStyle::Detailed));
+    }
+}
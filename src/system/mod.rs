@@ -1,7 +1,21 @@
 pub mod cpu;
 pub mod component;
+#[cfg(target_os = "windows")]
 pub mod gpu;
 pub mod disk;
 pub mod memory;
 pub mod os;
+pub mod battery;
+pub mod network;
+pub mod uptime;
+pub mod session;
+pub mod terminal;
+pub mod packages;
+pub mod sensors;
+pub mod memory_modules;
+pub mod board;
+pub mod security;
+pub mod numa;
+pub mod storage;
+#[cfg(target_os = "windows")]
 pub mod nvidia_gpu;
\ No newline at end of file
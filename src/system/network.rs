@@ -0,0 +1,155 @@
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+use crate::cli::args::DisplayStyle;
+use crate::system::component::{to_json, Component};
+use crate::utils::format::format_rate;
+
+#[derive(Debug, Default, Serialize)]
+pub struct NetworkInfo {
+    pub name: String,                   // Interface name
+    pub state: Option<String>,          // Operational state, up, down, dormant...
+    pub mac_address: Option<String>,
+    pub ipv4: Vec<String>,              // Address with prefix length, e.g. 192.168.1.2/24
+    pub ipv6: Vec<String>,
+    pub mtu: Option<u64>,
+    pub speed: Option<u64>,             // Link speed, Mb/s
+    pub duplex: Option<String>,
+    pub is_default_route: bool,         // Whether the interface holds an IPv4 or IPv6 default route
+    pub is_loopback: bool,
+    pub is_virtual: bool,               // Docker bridges, veth pairs, tun devices...
+    pub throughput: Option<NetworkThroughput>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkThroughput {
+    pub received_per_second: f64,       // Byte
+    pub transmitted_per_second: f64,    // Byte
+}
+
+impl Component for NetworkInfo {
+    fn get_info(&self, style: DisplayStyle) -> String {
+        let info = format!("{}:", "Network".blue());
+
+        // Write interface name, first IPv4 address and default route mark
+        let info = format!("{} {}", info, self.name);
+        let info = match self.ipv4.first() {
+            Some(address) => format!("{} {}", info, address),
+            None => info,
+        };
+        let info = match self.is_default_route {
+            true => format!("{} {}", info, "(default route)".green()),
+            false => info,
+        };
+
+        if style == DisplayStyle::Minimal {
+            return info;
+        }
+
+        // Write state, MAC address and IP addresses
+        let info = format!("{}\n\t- {}: {}", info, "State".yellow(), self.state.as_deref().unwrap_or("unknown"));
+        let info = match &self.mac_address {
+            Some(mac_address) => format!("{}\n\t- {}: {}", info, "MAC address".yellow(), mac_address),
+            None => info,
+        };
+        let info = match self.ipv4.is_empty() {
+            true => info,
+            false => format!("{}\n\t- {}: {}", info, "IPv4".yellow(), self.ipv4.join(", ")),
+        };
+        let info = match self.ipv6.is_empty() {
+            true => info,
+            false => format!("{}\n\t- {}: {}", info, "IPv6".yellow(), self.ipv6.join(", ")),
+        };
+
+        if style == DisplayStyle::Default {
+            return info;
+        }
+
+        // Write MTU, link speed and duplex
+        let info = match self.mtu {
+            Some(mtu) => format!("{}\n\t- {}: {}", info, "MTU".yellow(), mtu),
+            None => info,
+        };
+        let info = match self.speed {
+            Some(speed) => format!("{}\n\t- {}: {}Mb/s", info, "Link speed".yellow(), speed),
+            None => info,
+        };
+        let info = match &self.duplex {
+            Some(duplex) => format!("{}\n\t- {}: {}", info, "Duplex".yellow(), duplex),
+            None => info,
+        };
+
+        // Write receive and transmit rates
+        let info = match &self.throughput {
+            Some(throughput) => {
+                let info = format!("{}\n\t- {}: {}", info, "Receive rate".yellow(), format_rate(throughput.received_per_second));
+                format!("{}\n\t- {}: {}", info, "Transmit rate".yellow(), format_rate(throughput.transmitted_per_second))
+            }
+            None => info,
+        };
+
+        info
+    }
+
+    fn get_json(&self) -> Value {
+        to_json("network", self)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DnsInfo {
+    servers: Vec<String>,
+}
+
+impl DnsInfo {
+    pub fn new(servers: Vec<String>) -> DnsInfo {
+        DnsInfo { servers }
+    }
+}
+
+impl Component for DnsInfo {
+    fn get_info(&self, _style: DisplayStyle) -> String {
+        format!("{}: {}", "DNS".blue(), self.servers.join(", "))
+    }
+
+    fn get_json(&self) -> Value {
+        to_json("dns", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::args::DisplayStyle;
+    use crate::system::component::Component;
+    use crate::system::network::{DnsInfo, NetworkInfo, NetworkThroughput};
+
+    #[test]
+    fn it_works() {
+        let network = NetworkInfo {
+            name: "eth0".into(),
+            state: Some("up".into()),
+            mac_address: Some("52:54:00:12:34:56".into()),
+            ipv4: vec!["192.168.1.2/24".into()],
+            ipv6: vec!["fe80::5054:ff:fe12:3456/64".into()],
+            mtu: Some(1500),
+            speed: Some(1000),
+            duplex: Some("full".into()),
+            is_default_route: true,
+            throughput: Some(NetworkThroughput {
+                received_per_second: 1536f64,
+                transmitted_per_second: 3f64 * 1024f64.powi(2),
+            }),
+            ..Default::default()
+        };
+
+        let info = network.get_info(DisplayStyle::Minimal);
+        assert!(info.contains("eth0 192.168.1.2/24"));
+
+        let info = network.get_info(DisplayStyle::Detailed);
+        assert!(info.contains("1.5KB/s"));
+        assert!(info.contains("3.0MB/s"));
+
+        println!("{}", info);
+        println!("{}", DnsInfo::new(vec!["1.1.1.1".into()]).get_info(DisplayStyle::Default));
+    }
+}
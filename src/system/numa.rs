@@ -0,0 +1,128 @@
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+use crate::cli::args::DisplayStyle;
+use crate::system::component::{to_json, Component};
+use crate::utils::sysfs::format_cpu_list;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct PciDevice {
+    pub address: String,            // e.g. 0000:17:00.0
+    pub class: String,              // Network, Display, Storage or Accelerator
+    pub driver: Option<String>,
+    pub interfaces: Vec<String>,    // Network interfaces of the device, e.g. ens1f0
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct NumaNode {
+    pub id: usize,
+    pub cpus: Vec<usize>,
+    pub memory_total: u64,          // Byte
+    pub memory_free: u64,           // Byte
+    pub distances: Vec<u32>,        // Relative distance to each node, 10 being local
+    pub devices: Vec<PciDevice>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct NumaInfo {
+    pub nodes: Vec<NumaNode>,
+}
+
+fn gb(bytes: u64) -> f64 {
+    bytes as f64 / 1024f64.powi(3)
+}
+
+impl Component for NumaInfo {
+    fn get_info(&self, style: DisplayStyle) -> String {
+        let info = format!("{}:", "NUMA".blue());
+
+        // Write node count
+        let info = format!("{} {} nodes", info, self.nodes.len());
+
+        if style == DisplayStyle::Minimal {
+            return info;
+        }
+
+        // Write CPUs and memory of each node
+        let info = self.nodes.iter().fold(info, |info, node| {
+            let cpus = match node.cpus.is_empty() {
+                true => "no CPU".to_string(),
+                false => format!("CPU {}", format_cpu_list(&node.cpus)),
+            };
+            format!("{}\n\t- {}: {}, {:.1}GB free of {:.1}GB", info, format!("Node {}", node.id).yellow(), cpus,
+                gb(node.memory_free), gb(node.memory_total),
+            )
+        });
+
+        if style == DisplayStyle::Default {
+            return info;
+        }
+
+        // Write distance matrix, e.g. "node0: 10 21"
+        let header = self.nodes.iter().map(|node| format!("{:>7}", format!("node{}", node.id))).collect::<String>();
+        let info = format!("{}\n\t- {}:\n\t\t{:<7}{}", info, "Distances".yellow(), "", header);
+        let info = self.nodes.iter().fold(info, |info, node| {
+            let distances = node.distances.iter().map(|distance| format!("{:>7}", distance)).collect::<String>();
+            format!("{}\n\t\t{:<7}{}", info, format!("node{}", node.id), distances)
+        });
+
+        // Write PCI devices attached to each node
+        self.nodes.iter().filter(|node| !node.devices.is_empty()).fold(info, |info, node| {
+            let info = format!("{}\n\t- {}:", info, format!("Node {} devices", node.id).yellow());
+            node.devices.iter().fold(info, |info, device| {
+                let mut fields = vec![device.address.clone(), device.class.clone()];
+                fields.extend(device.driver.clone());
+                if !device.interfaces.is_empty() {
+                    fields.push(format!("({})", device.interfaces.join(", ")));
+                }
+                format!("{}\n\t\t{}", info, fields.join(" "))
+            })
+        })
+    }
+
+    fn get_json(&self) -> Value {
+        to_json("numa", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::args::DisplayStyle;
+    use crate::system::component::Component;
+    use crate::system::numa::{NumaInfo, NumaNode, PciDevice};
+
+    #[test]
+    fn it_works() {
+        let numa = NumaInfo {
+            nodes: vec![
+                NumaNode {
+                    id: 0,
+                    cpus: (0..28).chain(56..84).collect(),
+                    memory_total: 128 * 1024u64.pow(3),
+                    memory_free: 100 * 1024u64.pow(3),
+                    distances: vec![10, 21],
+                    devices: vec![PciDevice { address: "0000:17:00.0".into(), class: "Network".into(), driver: Some("mlx5_core".into()), interfaces: vec!["ens1f0".into()] }],
+                },
+                NumaNode { id: 1, cpus: (28..56).chain(84..112).collect(), distances: vec![21, 10], ..Default::default() },
+            ],
+        };
+
+        assert!(numa.get_info(DisplayStyle::Default).contains("CPU 0-27,56-83, 100.0GB free of 128.0GB"));
+        let info = numa.get_info(DisplayStyle::Detailed);
+        assert!(info.contains("\n\t\t         node0  node1\n"));
+        assert!(info.contains("node1       21     10"));
+        assert!(info.contains("0000:17:00.0 Network mlx5_core (ens1f0)"));
+
+        println!("{}", info);
+    }
+
+    #[test]
+    fn aligns_distances() {
+        let node = |id: usize, distances: Vec<u32>| NumaNode { id, distances, ..Default::default() };
+        let numa = NumaInfo { nodes: vec![node(9, vec![10, 12]), node(10, vec![12, 10])] };
+
+        let info = numa.get_info(DisplayStyle::Detailed);
+        assert!(info.contains("\n\t\t         node9 node10\n"));
+        assert!(info.contains("\n\t\tnode10      12     10"));
+    }
+}
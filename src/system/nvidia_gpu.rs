@@ -1,8 +1,10 @@
 use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
 use crate::cli::args::DisplayStyle;
-use crate::system::component::Component;
+use crate::system::component::{to_json, Component};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct NvidiaGpuInfo {
     pub name: String,
     pub driver_version: String,
@@ -88,4 +90,8 @@ impl Component for NvidiaGpuInfo {
 
         info
     }
-}
\ No newline at end of file
+
+    fn get_json(&self) -> Value {
+        to_json("nvidia_gpu", self)
+    }
+}
@@ -1,11 +1,58 @@
 use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
 use crate::cli::args::DisplayStyle;
-use crate::system::component::Component;
+use crate::system::component::{to_json, Component};
 
+#[derive(Default, Serialize)]
 pub struct OSInfo {
     name: String,
     host_name: String,
+    pub kernel_name: Option<String>,        // e.g. Linux
+    pub kernel_release: Option<String>,     // e.g. 6.8.0-45-generic
+    pub architecture: Option<String>,       // Machine architecture, e.g. x86_64
+    pub os_release: OsRelease,
+    pub init_system: Option<String>,
+    pub taint: Option<u64>,                 // Raw value of /proc/sys/kernel/tainted
+    pub taint_flags: Vec<String>,           // Decoded taint bits
+    pub command_line: Option<String>,       // Kernel command line
+    pub virtualization: Virtualization,
+}
+
+/// Identification fields of /etc/os-release.
+#[derive(Debug, Default, Serialize)]
+pub struct OsRelease {
+    pub id: Option<String>,
+    pub version_id: Option<String>,
+    pub version_codename: Option<String>,
+    pub build_id: Option<String>,
+    pub variant: Option<String>,
+}
 
+/// Whether the system runs on bare metal, in a virtual machine, a container or WSL.
+#[derive(Debug, Default, Serialize)]
+pub struct Virtualization {
+    pub environment: String,            // bare-metal, vm, container or wsl
+    pub hypervisor: Option<String>,     // e.g. KVM, QEMU, VMware, Hyper-V, VirtualBox, Xen
+    pub container: Option<String>,      // e.g. Docker, Podman, LXC, systemd-nspawn, Kubernetes
+    pub wsl: Option<u8>,                // WSL version, 1 or 2
+}
+
+impl Virtualization {
+    /// Short description, e.g. "Docker container on KVM" or "WSL2".
+    pub fn summary(&self) -> String {
+        let machine = match (&self.wsl, &self.hypervisor) {
+            (Some(version), _) => format!("WSL{}", version),
+            (None, Some(hypervisor)) => format!("{} virtual machine", hypervisor),
+            (None, None) => "Bare metal".to_string(),
+        };
+
+        match &self.container {
+            Some(container) if self.wsl.is_some() || self.hypervisor.is_some() => format!("{} container on {}", container, machine.trim_end_matches(" virtual machine")),
+            Some(container) => format!("{} container", container),
+            None => machine,
+        }
+    }
 }
 
 impl OSInfo {
@@ -16,6 +63,7 @@ impl OSInfo {
         OSInfo {
             name,
             host_name,
+            ..Default::default()
         }
     }
 }
@@ -31,16 +79,73 @@ impl Component for OSInfo {
 
         // Write host name
         let info = format!("{}\n\t- {}: {}", info, "Host name".yellow(), self.host_name);
+        // Write bare metal, virtual machine, container or WSL
+        let info = match self.virtualization.environment.is_empty() {
+            true => info,
+            false => format!("{}\n\t- {}: {}", info, "Virtualization".yellow(), self.virtualization.summary()),
+        };
+
+        if style == DisplayStyle::Default {
+            return info;
+        }
+
+        // Write kernel and architecture
+        let kernel = [&self.kernel_name, &self.kernel_release].iter()
+            .filter_map(|part| part.as_deref())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let info = match kernel.is_empty() {
+            true => info,
+            false => format!("{}\n\t- {}: {}", info, "Kernel".yellow(), kernel),
+        };
+        let info = match &self.architecture {
+            Some(architecture) => format!("{}\n\t- {}: {}", info, "Architecture".yellow(), architecture),
+            None => info,
+        };
+
+        // Write os-release identification
+        let release = [
+            ("ID", &self.os_release.id),
+            ("Version ID", &self.os_release.version_id),
+            ("Codename", &self.os_release.version_codename),
+            ("Build ID", &self.os_release.build_id),
+            ("Variant", &self.os_release.variant),
+        ];
+        let info = release.iter().fold(info, |info, (label, value)| match value {
+            Some(value) => format!("{}\n\t- {}: {}", info, label.yellow(), value),
+            None => info,
+        });
+
+        // Write init system, taint state and command line
+        let info = match &self.init_system {
+            Some(init_system) => format!("{}\n\t- {}: {}", info, "Init system".yellow(), init_system),
+            None => info,
+        };
+        let info = match self.taint {
+            Some(0) => format!("{}\n\t- {}: {}", info, "Kernel taint".yellow(), "Not tainted"),
+            Some(taint) => format!("{}\n\t- {}: {}{}", info, "Kernel taint".yellow(), taint,
+                self.taint_flags.iter().map(|flag| format!("\n\t\t{}", flag)).collect::<String>(),
+            ),
+            None => info,
+        };
+        let info = match &self.command_line {
+            Some(command_line) => format!("{}\n\t- {}: {}", info, "Command line".yellow(), command_line),
+            None => info,
+        };
 
         info
     }
+
+    fn get_json(&self) -> Value {
+        to_json("os", self)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::cli::args::DisplayStyle;
     use crate::system::component::Component;
-    use crate::system::os::OSInfo;
+    use crate::system::os::{OSInfo, Virtualization};
 
     #[test]
     fn sys_info_test() {
@@ -51,4 +156,38 @@ mod tests {
 
         println!("{}", info.get_info(DisplayStyle::Minimal));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn detailed_kernel_info() {
+        let mut info = OSInfo::new("Debian GNU/Linux 12".into(), "build-01".into());
+        info.kernel_name = Some("Linux".into());
+        info.kernel_release = Some("6.1.0-26-amd64".into());
+        info.architecture = Some("x86_64".into());
+        info.taint = Some(4608);
+        info.taint_flags = vec!["W (kernel issued warning)".into(), "O (externally-built (out-of-tree) module was loaded)".into()];
+
+        assert!(!info.get_info(DisplayStyle::Default).contains("Kernel"));
+
+        let detailed = info.get_info(DisplayStyle::Detailed);
+        assert!(detailed.contains("Linux 6.1.0-26-amd64"));
+        assert!(detailed.contains("W (kernel issued warning)"));
+
+        println!("{}", detailed);
+    }
+
+    #[test]
+    fn summarizes_virtualization() {
+        let summary = |hypervisor: Option<&str>, container: Option<&str>, wsl: Option<u8>| Virtualization {
+            hypervisor: hypervisor.map(|name| name.into()),
+            container: container.map(|name| name.into()),
+            wsl,
+            ..Default::default()
+        }.summary();
+
+        assert_eq!(summary(None, None, None), "Bare metal");
+        assert_eq!(summary(Some("KVM"), None, None), "KVM virtual machine");
+        assert_eq!(summary(None, Some("Docker"), None), "Docker container");
+        assert_eq!(summary(Some("KVM"), Some("Kubernetes"), None), "Kubernetes container on KVM");
+        assert_eq!(summary(Some("Hyper-V"), Some("Docker"), Some(2)), "Docker container on WSL2");
+    }
+}
@@ -0,0 +1,62 @@
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+use crate::cli::args::DisplayStyle;
+use crate::system::component::{to_json, Component};
+
+#[derive(Debug, Serialize)]
+pub struct PackageCount {
+    pub manager: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PackagesInfo {
+    counts: Vec<PackageCount>,
+}
+
+impl PackagesInfo {
+    pub fn new(counts: Vec<PackageCount>) -> PackagesInfo {
+        PackagesInfo { counts }
+    }
+}
+
+impl Component for PackagesInfo {
+    fn get_info(&self, style: DisplayStyle) -> String {
+        let info = format!("{}:", "Packages".blue());
+
+        if style == DisplayStyle::Minimal {
+            let total = self.counts.iter().map(|count| count.count).sum::<usize>();
+            return format!("{} {}", info, total);
+        }
+
+        // Write count of each package manager, e.g. "1523 (dpkg), 42 (flatpak)"
+        let counts = self.counts.iter()
+            .map(|count| format!("{} ({})", count.count, count.manager))
+            .collect::<Vec<_>>();
+
+        format!("{} {}", info, counts.join(", "))
+    }
+
+    fn get_json(&self) -> Value {
+        to_json("packages", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::args::DisplayStyle;
+    use crate::system::component::Component;
+    use crate::system::packages::{PackageCount, PackagesInfo};
+
+    #[test]
+    fn it_works() {
+        let packages = PackagesInfo::new(vec![
+            PackageCount { manager: "dpkg".into(), count: 1523 },
+            PackageCount { manager: "flatpak".into(), count: 42 },
+        ]);
+
+        assert!(packages.get_info(DisplayStyle::Default).ends_with("1523 (dpkg), 42 (flatpak)"));
+        assert!(packages.get_info(DisplayStyle::Minimal).ends_with("1565"));
+    }
+}
@@ -0,0 +1,103 @@
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+use crate::cli::args::DisplayStyle;
+use crate::system::component::{to_json, Component};
+
+/// Platform security state. `None` means the state could not be read, e.g. without root or
+/// when securityfs is not mounted, and is shown as "unknown".
+#[derive(Debug, Default, Serialize)]
+pub struct SecurityInfo {
+    pub boot_mode: Option<String>,              // UEFI or BIOS
+    pub secure_boot: Option<bool>,
+    pub setup_mode: Option<bool>,               // Secure Boot keys not enrolled yet
+    pub tpm_version: Option<String>,            // 1.2 or 2.0, "none" without TPM
+    pub security_modules: Option<Vec<String>>,  // Active LSMs, e.g. lockdown, yama, apparmor
+    pub selinux: Option<String>,                // enforcing, permissive or disabled
+    pub apparmor: Option<String>,               // enabled or disabled
+    pub apparmor_profiles: Option<AppArmorProfiles>,
+    pub lockdown: Option<String>,               // none, integrity or confidentiality
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct AppArmorProfiles {
+    pub enforce: usize,
+    pub complain: usize,
+}
+
+fn or_unknown(value: Option<&str>) -> String {
+    value.unwrap_or("unknown").to_string()
+}
+
+fn on_off(value: Option<bool>) -> String {
+    match value {
+        Some(true) => "enabled".green().to_string(),
+        Some(false) => "disabled".red().to_string(),
+        None => "unknown".to_string(),
+    }
+}
+
+impl Component for SecurityInfo {
+    fn get_info(&self, style: DisplayStyle) -> String {
+        let info = format!("{}:", "Security".blue());
+
+        // Write boot mode and Secure Boot, e.g. "UEFI, Secure Boot enabled"
+        let info = format!("{} {}, Secure Boot {}", info, or_unknown(self.boot_mode.as_deref()), on_off(self.secure_boot));
+
+        if style == DisplayStyle::Minimal {
+            return info;
+        }
+
+        // Write TPM, security modules and lockdown
+        let info = format!("{}\n\t- {}: {}", info, "TPM".yellow(), or_unknown(self.tpm_version.as_deref()));
+        let security_modules = self.security_modules.as_ref().map(|modules| modules.join(", "));
+        let info = format!("{}\n\t- {}: {}", info, "Security modules".yellow(), or_unknown(security_modules.as_deref()));
+        let info = format!("{}\n\t- {}: {}", info, "SELinux".yellow(), or_unknown(self.selinux.as_deref()));
+        let apparmor = match (&self.apparmor, &self.apparmor_profiles) {
+            (Some(apparmor), Some(profiles)) => format!("{} ({} enforce, {} complain)", apparmor, profiles.enforce, profiles.complain),
+            (apparmor, _) => or_unknown(apparmor.as_deref()),
+        };
+        let info = format!("{}\n\t- {}: {}", info, "AppArmor".yellow(), apparmor);
+        let info = format!("{}\n\t- {}: {}", info, "Lockdown".yellow(), or_unknown(self.lockdown.as_deref()));
+
+        if style == DisplayStyle::Default {
+            return info;
+        }
+
+        // Write Secure Boot setup mode
+        format!("{}\n\t- {}: {}", info, "Setup mode".yellow(), match self.setup_mode {
+            Some(true) => "yes".red().to_string(),
+            Some(false) => "no".to_string(),
+            None => "unknown".to_string(),
+        })
+    }
+
+    fn get_json(&self) -> Value {
+        to_json("security", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::args::DisplayStyle;
+    use crate::system::component::Component;
+    use crate::system::security::SecurityInfo;
+
+    #[test]
+    fn it_works() {
+        let security = SecurityInfo {
+            boot_mode: Some("UEFI".into()),
+            tpm_version: Some("2.0".into()),
+            security_modules: Some(vec!["capability".into(), "lockdown".into(), "apparmor".into()]),
+            apparmor: Some("enabled".into()),
+            ..Default::default()
+        };
+
+        assert!(security.get_info(DisplayStyle::Minimal).ends_with("UEFI, Secure Boot unknown"));
+        let info = security.get_info(DisplayStyle::Detailed);
+        assert!(info.contains("capability, lockdown, apparmor"));
+        assert!(info.contains("Lockdown"));
+
+        println!("{}", info);
+    }
+}
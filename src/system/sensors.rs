@@ -0,0 +1,136 @@
+use colored::{ColoredString, Colorize};
+use serde::Serialize;
+use serde_json::Value;
+use crate::cli::args::DisplayStyle;
+use crate::system::component::{to_json, Component};
+
+#[derive(Debug, Default, Serialize)]
+pub struct SensorReading {
+    pub label: String,
+    pub value: f64,                 // °C for temperatures, RPM for fans, V for voltages
+    pub min: Option<f64>,
+    pub high: Option<f64>,          // Maximum for fans and voltages
+    pub critical: Option<f64>,
+}
+
+impl SensorReading {
+    /// Color the value yellow from its high threshold (or 90% of the critical one), and red from the
+    /// critical threshold or below the minimum.
+    fn colored(&self, text: String) -> ColoredString {
+        let (warning, critical) = match (self.high, self.critical) {
+            (Some(high), Some(critical)) => (Some(high.min(critical * 0.9)), Some(critical)),
+            (Some(high), None) => (Some(high * 0.9), Some(high)),
+            (None, Some(critical)) => (Some(critical * 0.9), Some(critical)),
+            (None, None) => (None, None),
+        };
+        let below_min = self.min.is_some_and(|min| self.value < min);
+
+        match (warning, critical) {
+            (_, Some(critical)) if self.value >= critical => text.red(),
+            _ if below_min => text.red(),
+            (Some(warning), _) if self.value >= warning => text.yellow(),
+            _ => text.normal(),
+        }
+    }
+
+    fn thresholds(&self, unit: &str) -> String {
+        let thresholds = [("min", self.min), ("high", self.high), ("crit", self.critical)].iter()
+            .filter_map(|(name, value)| value.map(|value| format!("{} {}{}", name, value, unit)))
+            .collect::<Vec<_>>();
+
+        match thresholds.is_empty() {
+            true => String::new(),
+            false => format!(" ({})", thresholds.join(", ")),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct SensorsInfo {
+    pub chip: String,                       // hwmon chip name, e.g. coretemp, nvme, nct6798
+    pub device: Option<String>,             // Underlying device, e.g. nvme0
+    pub temperatures: Vec<SensorReading>,
+    pub fans: Vec<SensorReading>,
+    pub voltages: Vec<SensorReading>,
+}
+
+impl Component for SensorsInfo {
+    fn get_info(&self, style: DisplayStyle) -> String {
+        let info = format!("{}:", "Sensors".blue());
+
+        // Write chip name and hottest temperature
+        let info = format!("{} {}", info, self.chip);
+        let hottest = self.temperatures.iter().max_by(|a, b| a.value.total_cmp(&b.value));
+        let info = match hottest {
+            Some(reading) => format!("{} {}", info, reading.colored(format!("{:.1}°C", reading.value))),
+            None => info,
+        };
+
+        if style == DisplayStyle::Minimal {
+            return info;
+        }
+
+        let detailed = style == DisplayStyle::Detailed;
+        let line = |info: String, reading: &SensorReading, value: String, unit: &str| {
+            let thresholds = if detailed { reading.thresholds(unit) } else { String::new() };
+            format!("{}\n\t- {}: {}{}", info, reading.label.yellow(), reading.colored(value), thresholds)
+        };
+
+        // Write temperatures and fan speeds
+        let info = self.temperatures.iter().fold(info, |info, reading| line(info, reading, format!("{:.1}°C", reading.value), "°C"));
+        let info = self.fans.iter().fold(info, |info, reading| line(info, reading, format!("{:.0}rpm", reading.value), "rpm"));
+
+        if style == DisplayStyle::Default {
+            return info;
+        }
+
+        // Write voltages and the underlying device
+        let info = self.voltages.iter().fold(info, |info, reading| line(info, reading, format!("{:.3}V", reading.value), "V"));
+        let info = match &self.device {
+            Some(device) => format!("{}\n\t- {}: {}", info, "Device".yellow(), device),
+            None => info,
+        };
+
+        info
+    }
+
+    fn get_json(&self) -> Value {
+        to_json("sensors", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::args::DisplayStyle;
+    use crate::system::component::Component;
+    use crate::system::sensors::{SensorReading, SensorsInfo};
+
+    #[test]
+    fn it_works() {
+        let sensors = SensorsInfo {
+            chip: "coretemp".into(),
+            temperatures: vec![
+                SensorReading { label: "Package id 0".into(), value: 86.0, high: Some(80.0), critical: Some(100.0), ..Default::default() },
+                SensorReading { label: "Core 0".into(), value: 45.0, high: Some(80.0), critical: Some(100.0), ..Default::default() },
+            ],
+            fans: vec![SensorReading { label: "fan1".into(), value: 1200.0, ..Default::default() }],
+            ..Default::default()
+        };
+
+        assert!(sensors.get_info(DisplayStyle::Minimal).contains("86.0°C"));
+        assert!(sensors.get_info(DisplayStyle::Detailed).contains("(high 80°C, crit 100°C)"));
+
+        println!("{}", sensors.get_info(DisplayStyle::Detailed));
+    }
+
+    #[test]
+    fn colors_by_threshold() {
+        let reading = |value: f64| SensorReading { value, high: Some(80.0), critical: Some(100.0), ..Default::default() };
+        assert_eq!(reading(50.0).colored("x".into()).fgcolor, None);
+        assert_eq!(reading(85.0).colored("x".into()).fgcolor, Some(colored::Color::Yellow));
+        assert_eq!(reading(100.0).colored("x".into()).fgcolor, Some(colored::Color::Red));
+
+        let fan = SensorReading { value: 0.0, min: Some(300.0), ..Default::default() };
+        assert_eq!(fan.colored("x".into()).fgcolor, Some(colored::Color::Red));
+    }
+}
@@ -0,0 +1,88 @@
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+use crate::cli::args::DisplayStyle;
+use crate::system::component::{to_json, Component};
+
+#[derive(Debug, Default, Serialize)]
+pub struct SessionInfo {
+    pub desktop: Option<String>,            // Desktop environment, e.g. KDE Plasma
+    pub window_manager: Option<String>,     // Window manager or compositor, e.g. KWin
+    pub session_type: String,               // Wayland, X11, TTY or Headless
+    pub gtk_theme: Option<String>,
+    pub gtk_icon_theme: Option<String>,
+    pub gtk_font: Option<String>,
+    pub qt_style: Option<String>,
+    pub qt_icon_theme: Option<String>,
+    pub qt_font: Option<String>,
+}
+
+impl Component for SessionInfo {
+    fn get_info(&self, style: DisplayStyle) -> String {
+        let info = format!("{}:", "Session".blue());
+
+        // Write desktop environment and session type
+        let info = match &self.desktop {
+            Some(desktop) => format!("{} {} ({})", info, desktop, self.session_type),
+            None => format!("{} {}", info, self.session_type),
+        };
+
+        if style == DisplayStyle::Minimal {
+            return info;
+        }
+
+        // Write window manager
+        let info = match &self.window_manager {
+            Some(window_manager) => format!("{}\n\t- {}: {}", info, "Window manager".yellow(), window_manager),
+            None => info,
+        };
+
+        if style == DisplayStyle::Default {
+            return info;
+        }
+
+        // Write GTK and Qt appearance
+        let appearance = [
+            ("GTK theme", &self.gtk_theme),
+            ("GTK icon theme", &self.gtk_icon_theme),
+            ("GTK font", &self.gtk_font),
+            ("Qt style", &self.qt_style),
+            ("Qt icon theme", &self.qt_icon_theme),
+            ("Qt font", &self.qt_font),
+        ];
+        let info = appearance.iter().fold(info, |info, (label, value)| match value {
+            Some(value) => format!("{}\n\t- {}: {}", info, label.yellow(), value),
+            None => info,
+        });
+
+        info
+    }
+
+    fn get_json(&self) -> Value {
+        to_json("session", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::args::DisplayStyle;
+    use crate::system::component::Component;
+    use crate::system::session::SessionInfo;
+
+    #[test]
+    fn it_works() {
+        let session = SessionInfo {
+            desktop: Some("KDE Plasma".into()),
+            window_manager: Some("KWin".into()),
+            session_type: "Wayland".into(),
+            qt_style: Some("Breeze".into()),
+            qt_icon_theme: Some("breeze-dark".into()),
+            qt_font: Some("Noto Sans 10".into()),
+            ..Default::default()
+        };
+
+        assert!(session.get_info(DisplayStyle::Minimal).ends_with("KDE Plasma (Wayland)"));
+
+        println!("{}", session.get_info(DisplayStyle::Detailed));
+    }
+}
@@ -0,0 +1,323 @@
+use std::collections::HashSet;
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+use crate::cli::args::DisplayStyle;
+use crate::system::component::{to_json, Component};
+
+/// A physical block device from /sys/block, e.g. an NVMe drive or a USB stick.
+#[derive(Debug, Default, Serialize)]
+pub struct BlockDevice {
+    pub name: String,                   // Kernel name, e.g. nvme0n1, sda, vda
+    pub model: Option<String>,
+    pub vendor: Option<String>,
+    pub serial: Option<String>,         // Only filled with --show-serials
+    pub size: u64,                      // Byte
+    pub rotational: bool,               // Spinning disk rather than solid-state
+    pub removable: bool,
+    pub transport: Option<String>,      // NVMe, SATA, SAS, USB, virtio, MMC or SCSI
+    pub logical_sector_size: u64,       // Byte
+    pub physical_sector_size: u64,      // Byte
+    pub mounts: Vec<MountUsage>,        // Filesystems on the whole device, without partition table
+    pub partitions: Vec<Partition>,
+    pub holders: Vec<String>,           // Volumes built on the whole device, e.g. md0, dm-0
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct Partition {
+    pub name: String,                   // e.g. nvme0n1p2
+    pub size: u64,                      // Byte
+    pub mounts: Vec<MountUsage>,
+    pub holders: Vec<String>,
+}
+
+/// A device mapper or md device stacked on other block devices.
+#[derive(Debug, Default, Serialize)]
+pub struct Volume {
+    pub name: String,                   // Kernel name, e.g. dm-0, md0
+    pub label: Option<String>,          // Device mapper name, e.g. vg0-root, luks-0b1c...
+    pub kind: String,                   // LVM, crypt, multipath, RAID or dm
+    pub size: u64,                      // Byte
+    pub members: Vec<String>,           // Devices the volume is built on
+    pub raid: Option<RaidArray>,
+    pub mounts: Vec<MountUsage>,
+    pub holders: Vec<String>,
+}
+
+/// md array state from /proc/mdstat.
+#[derive(Debug, Default, Serialize)]
+pub struct RaidArray {
+    pub level: String,                  // e.g. raid1, raid5
+    pub devices: usize,                 // Member slots of the array
+    pub active: usize,                  // Members in sync
+    pub failed: Vec<String>,            // Members marked faulty
+    pub sync: Option<String>,           // Running operation, e.g. "recovery 12.6%"
+}
+
+impl RaidArray {
+    pub fn degraded(&self) -> bool {
+        self.active < self.devices
+    }
+}
+
+/// A btrfs filesystem spanning several devices.
+#[derive(Debug, Default, Serialize)]
+pub struct BtrfsFilesystem {
+    pub uuid: String,
+    pub label: Option<String>,
+    pub profile: Option<String>,        // Data profile, e.g. raid1, single
+    pub devices: Vec<String>,
+    pub mounts: Vec<MountUsage>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct MountUsage {
+    pub mount_point: String,
+    pub file_system: String,
+    pub total_space: u64,               // Byte
+    pub available_space: u64,           // Byte
+}
+
+impl BlockDevice {
+    /// e.g. "NVMe SSD", "USB HDD"
+    pub fn kind(&self) -> String {
+        let medium = match self.rotational {
+            true => "HDD",
+            false => "SSD",
+        };
+
+        match &self.transport {
+            Some(transport) => format!("{} {}", transport, medium),
+            None => medium.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct StorageInfo {
+    pub devices: Vec<BlockDevice>,
+    pub volumes: Vec<Volume>,
+    pub btrfs: Vec<BtrfsFilesystem>,
+}
+
+impl StorageInfo {
+    /// Volumes stacked on `holders` and what is mounted from them, one level deeper each time. A volume
+    /// spanning several members is written under the first one, the others only refer to it.
+    fn holder_tree(&self, info: String, holders: &[String], depth: usize, written: &mut HashSet<String>) -> String {
+        holders.iter()
+            .filter_map(|name| self.volumes.iter().find(|volume| &volume.name == name))
+            .fold(info, |info, volume| {
+                let indent = "  ".repeat(depth);
+                let name = match &volume.label {
+                    Some(label) => format!("{} ({})", label, volume.name),
+                    None => volume.name.clone(),
+                };
+                if !written.insert(volume.name.clone()) {
+                    return format!("{}\n\t\t{}└ → {}", info, indent, name);
+                }
+
+                let mut fields = [volume.kind.clone(), format!("{:.1}GB", gb(volume.size))];
+                if let Some(raid) = &volume.raid {
+                    // e.g. "degraded, sdc1 failed, recovery 12.6%", in red
+                    let mut state = vec![match raid.degraded() {
+                        true => "degraded".to_string(),
+                        false => "clean".to_string(),
+                    }];
+                    state.extend(raid.failed.iter().map(|member| format!("{} failed", member)));
+                    state.extend(raid.sync.clone());
+                    let state = match raid.degraded() {
+                        true => state.join(", ").red().to_string(),
+                        false => state.join(", "),
+                    };
+                    fields[0] = format!("{} [{}/{}] {}", raid.level, raid.active, raid.devices, state);
+                }
+                let info = format!("{}\n\t\t{}└ {}: {}", info, indent, name, fields.join(", "));
+                let info = match volume.mounts.is_empty() {
+                    true => info,
+                    false => format!("{}\n\t\t{}  └ {}", info, indent, format_mounts(&volume.mounts)),
+                };
+
+                self.holder_tree(info, &volume.holders, depth + 1, written)
+            })
+    }
+}
+
+fn gb(bytes: u64) -> f64 {
+    bytes as f64 / 1024f64.powi(3)
+}
+
+/// e.g. "/ (ext4) 120.3GB/915.4GB"
+fn format_mounts(mounts: &[MountUsage]) -> String {
+    mounts.iter()
+        .map(|mount| format!("{} ({}) {:.1}GB/{:.1}GB", mount.mount_point, mount.file_system,
+            gb(mount.total_space.saturating_sub(mount.available_space)), gb(mount.total_space),
+        ))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl Component for StorageInfo {
+    fn get_info(&self, style: DisplayStyle) -> String {
+        let info = format!("{}:", "Storage".blue());
+
+        // Write device count and total capacity, and whether an array is degraded
+        let info = format!("{} {} devices, {:.1}GB", info, self.devices.len(), gb(self.devices.iter().map(|device| device.size).sum()));
+        let degraded = self.volumes.iter().filter(|volume| volume.raid.as_ref().is_some_and(|raid| raid.degraded())).count();
+        let info = match degraded {
+            0 => info,
+            degraded => format!("{}, {}", info, format!("{} degraded arrays", degraded).red()),
+        };
+
+        if style == DisplayStyle::Minimal {
+            return info;
+        }
+
+        // Write each device with its partitions, the volumes stacked on them and mount points nested underneath
+        let mut written = HashSet::new();
+        let info = self.devices.iter().fold(info, |info, device| {
+            let mut fields = device.model.iter().cloned().collect::<Vec<_>>();
+            fields.push(format!("{:.1}GB", gb(device.size)));
+            fields.push(device.kind());
+            if device.removable {
+                fields.push("removable".to_string());
+            }
+            let info = format!("{}\n\t- {}: {}", info, device.name.yellow(), fields.join(", "));
+
+            let info = match style {
+                DisplayStyle::Detailed => {
+                    let details = [
+                        ("Vendor", device.vendor.clone()),
+                        ("Serial", device.serial.clone()),
+                        ("Sector size", Some(format!("{} bytes logical, {} bytes physical", device.logical_sector_size, device.physical_sector_size))),
+                    ];
+                    details.iter().fold(info, |info, (label, value)| match value {
+                        Some(value) => format!("{}\n\t\t{}: {}", info, label, value),
+                        None => info,
+                    })
+                }
+                _ => info,
+            };
+
+            let info = match device.mounts.is_empty() {
+                true => info,
+                false => format!("{}\n\t\t{}", info, format_mounts(&device.mounts)),
+            };
+            let info = self.holder_tree(info, &device.holders, 0, &mut written);
+
+            device.partitions.iter().fold(info, |info, partition| {
+                let info = match (partition.mounts.is_empty(), partition.holders.is_empty()) {
+                    (false, _) => format!("{}\n\t\t{}: {:.1}GB, {}", info, partition.name, gb(partition.size), format_mounts(&partition.mounts)),
+                    (true, false) => format!("{}\n\t\t{}: {:.1}GB", info, partition.name, gb(partition.size)),
+                    (true, true) => format!("{}\n\t\t{}: {:.1}GB, not mounted", info, partition.name, gb(partition.size)),
+                };
+
+                self.holder_tree(info, &partition.holders, 1, &mut written)
+            })
+        });
+
+        // Write btrfs filesystems spanning several devices
+        self.btrfs.iter().fold(info, |info, filesystem| {
+            let name = filesystem.label.clone().unwrap_or_else(|| filesystem.uuid.clone());
+            let mut fields = vec![filesystem.devices.join(" + ")];
+            fields.extend(filesystem.profile.clone());
+            let info = format!("{}\n\t- {}: {}", info, format!("btrfs {}", name).yellow(), fields.join(", "));
+
+            match filesystem.mounts.is_empty() {
+                true => info,
+                false => format!("{}\n\t\t{}", info, format_mounts(&filesystem.mounts)),
+            }
+        })
+    }
+
+    fn get_json(&self) -> Value {
+        to_json("storage", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::args::DisplayStyle;
+    use crate::system::component::Component;
+    use crate::system::storage::{BlockDevice, MountUsage, Partition, RaidArray, StorageInfo, Volume};
+
+    #[test]
+    fn it_works() {
+        let gb = 1024u64.pow(3);
+        let storage = StorageInfo {
+            devices: vec![BlockDevice {
+                name: "nvme0n1".into(),
+                model: Some("Samsung SSD 980 PRO 1TB".into()),
+                size: 1000 * gb,
+                transport: Some("NVMe".into()),
+                logical_sector_size: 512,
+                physical_sector_size: 512,
+                partitions: vec![
+                    Partition {
+                        name: "nvme0n1p1".into(),
+                        size: gb / 2,
+                        mounts: vec![MountUsage { mount_point: "/boot/efi".into(), file_system: "vfat".into(), total_space: gb / 2, available_space: gb / 4 }],
+                        ..Default::default()
+                    },
+                    Partition { name: "nvme0n1p2".into(), size: 999 * gb, ..Default::default() },
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        assert!(storage.get_info(DisplayStyle::Minimal).ends_with("1 devices, 1000.0GB"));
+        let info = storage.get_info(DisplayStyle::Default);
+        assert!(info.contains("Samsung SSD 980 PRO 1TB, 1000.0GB, NVMe SSD"));
+        assert!(info.contains("nvme0n1p1: 0.5GB, /boot/efi (vfat) 0.2GB/0.5GB"));
+        assert!(info.contains("nvme0n1p2: 999.0GB, not mounted"));
+        assert!(!info.contains("Sector size"));
+
+        println!("{}", storage.get_info(DisplayStyle::Detailed));
+    }
+
+    #[test]
+    fn shows_storage_stack() {
+        let gb = 1024u64.pow(3);
+        let disk = |name: &str| BlockDevice {
+            name: name.into(),
+            size: 1000 * gb,
+            partitions: vec![Partition { name: format!("{}1", name), size: 1000 * gb, holders: vec!["md0".into()], ..Default::default() }],
+            ..Default::default()
+        };
+        let storage = StorageInfo {
+            devices: vec![disk("sda"), disk("sdb")],
+            volumes: vec![
+                Volume {
+                    name: "md0".into(),
+                    kind: "RAID".into(),
+                    size: 1000 * gb,
+                    members: vec!["sda1".into(), "sdb1".into()],
+                    raid: Some(RaidArray { level: "raid1".into(), devices: 2, active: 1, failed: vec!["sdb1".into()], sync: None }),
+                    holders: vec!["dm-0".into()],
+                    ..Default::default()
+                },
+                Volume {
+                    name: "dm-0".into(),
+                    label: Some("vg0-root".into()),
+                    kind: "LVM".into(),
+                    size: 500 * gb,
+                    members: vec!["md0".into()],
+                    mounts: vec![MountUsage { mount_point: "/".into(), file_system: "ext4".into(), total_space: 500 * gb, available_space: 400 * gb }],
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        assert!(storage.get_info(DisplayStyle::Minimal).contains("1 degraded arrays"));
+        let info = storage.get_info(DisplayStyle::Default);
+        assert!(info.contains("sda1: 1000.0GB\n\t\t  └ md0: raid1 [1/2] "));
+        assert!(info.contains("degraded, sdb1 failed"));
+        assert!(info.contains("    └ vg0-root (dm-0): LVM, 500.0GB\n\t\t      └ / (ext4) 100.0GB/500.0GB"));
+        // The array and what it holds are only written under its first member.
+        assert!(info.contains("sdb1: 1000.0GB\n\t\t  └ → md0"));
+        assert_eq!(info.matches("vg0-root").count(), 1);
+
+        println!("{}", info);
+    }
+}
@@ -0,0 +1,124 @@
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+use crate::cli::args::DisplayStyle;
+use crate::system::component::{to_json, Component};
+
+#[derive(Debug, Default, Serialize)]
+pub struct ShellInfo {
+    pub name: String,
+    pub version: Option<String>,
+    pub path: Option<String>,
+}
+
+impl Component for ShellInfo {
+    fn get_info(&self, style: DisplayStyle) -> String {
+        let info = format!("{}:", "Shell".blue());
+
+        // Write shell name and version
+        let info = format!("{} {}", info, self.name);
+        let info = match &self.version {
+            Some(version) => format!("{} {}", info, version),
+            None => info,
+        };
+
+        if style == DisplayStyle::Minimal {
+            return info;
+        }
+
+        // Write shell path
+        let info = match &self.path {
+            Some(path) => format!("{}\n\t- {}: {}", info, "Path".yellow(), path),
+            None => info,
+        };
+
+        info
+    }
+
+    fn get_json(&self) -> Value {
+        to_json("shell", self)
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct TerminalInfo {
+    pub name: String,
+    pub version: Option<String>,
+    pub columns: Option<u16>,
+    pub rows: Option<u16>,
+    pub colors: Option<String>,     // Truecolor, 256 or 16
+    pub term: Option<String>,       // Value of TERM
+}
+
+impl Component for TerminalInfo {
+    fn get_info(&self, style: DisplayStyle) -> String {
+        let info = format!("{}:", "Terminal".blue());
+
+        // Write terminal name and version
+        let info = format!("{} {}", info, self.name);
+        let info = match &self.version {
+            Some(version) => format!("{} {}", info, version),
+            None => info,
+        };
+
+        if style == DisplayStyle::Minimal {
+            return info;
+        }
+
+        // Write terminal size and color capability
+        let info = match (self.columns, self.rows) {
+            (Some(columns), Some(rows)) => format!("{}\n\t- {}: {}x{}", info, "Size".yellow(), columns, rows),
+            _ => info,
+        };
+        let info = match &self.colors {
+            Some(colors) => format!("{}\n\t- {}: {}", info, "Colors".yellow(), colors),
+            None => info,
+        };
+
+        if style == DisplayStyle::Default {
+            return info;
+        }
+
+        // Write TERM
+        let info = match &self.term {
+            Some(term) => format!("{}\n\t- {}: {}", info, "TERM".yellow(), term),
+            None => info,
+        };
+
+        info
+    }
+
+    fn get_json(&self) -> Value {
+        to_json("terminal", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::args::DisplayStyle;
+    use crate::system::component::Component;
+    use crate::system::terminal::{ShellInfo, TerminalInfo};
+
+    #[test]
+    fn it_works() {
+        let shell = ShellInfo {
+            name: "zsh".into(),
+            version: Some("5.9".into()),
+            path: Some("/usr/bin/zsh".into()),
+        };
+        let terminal = TerminalInfo {
+            name: "kitty".into(),
+            version: Some("0.32.2".into()),
+            columns: Some(120),
+            rows: Some(40),
+            colors: Some("Truecolor".into()),
+            term: Some("xterm-kitty".into()),
+        };
+
+        assert!(shell.get_info(DisplayStyle::Minimal).ends_with("zsh 5.9"));
+        assert!(terminal.get_info(DisplayStyle::Default).contains("120x40"));
+
+        println!("{}", shell.get_info(DisplayStyle::Detailed));
+        println!("{}", terminal.get_info(DisplayStyle::Detailed));
+    }
+}
@@ -0,0 +1,106 @@
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+use crate::cli::args::DisplayStyle;
+use crate::system::component::{to_json, Component};
+use crate::utils::format::format_timestamp;
+
+#[derive(Debug, Default, Serialize)]
+pub struct UptimeInfo {
+    pub uptime: u64,                // Second
+    pub boot_time: u64,             // Unix timestamp
+    pub load_average: [f64; 3],     // 1, 5 and 15 minutes
+    pub cpus: usize,                // Logical CPU count
+    pub processes: usize,
+    pub threads: Option<usize>,
+    pub pids_limit: Option<u64>,    // cgroup limit on processes and threads
+}
+
+/// Human readable duration. `units` limits how many of days, hours and minutes are written.
+fn format_uptime(seconds: u64, units: usize) -> String {
+    let parts = [(seconds / 86400, "d"), (seconds % 86400 / 3600, "h"), (seconds % 3600 / 60, "m")];
+
+    let parts = parts.iter()
+        .skip_while(|(value, _)| *value == 0)
+        .take(units)
+        .map(|(value, unit)| format!("{}{}", value, unit))
+        .collect::<Vec<_>>();
+
+    match parts.is_empty() {
+        true => "0m".to_string(),
+        false => parts.join(" "),
+    }
+}
+
+impl Component for UptimeInfo {
+    fn get_info(&self, style: DisplayStyle) -> String {
+        if style == DisplayStyle::Minimal {
+            return format!("{} {}, load {:.2}", "up".blue(), format_uptime(self.uptime, 2), self.load_average[0]);
+        }
+
+        // Write uptime and boot time
+        let info = format!("{}: {}", "Uptime".blue(), format_uptime(self.uptime, 3));
+        let info = format!("{}\n\t- {}: {}", info, "Boot time".yellow(), format_timestamp(self.boot_time));
+
+        // Write load average, relative to logical CPU count
+        let [one, five, fifteen] = self.load_average;
+        let info = format!("{}\n\t- {}: {:.2}, {:.2}, {:.2}", info, "Load average".yellow(), one, five, fifteen);
+        let info = match self.cpus {
+            0 => info,
+            cpus => format!("{} ({:.0}%, {:.0}%, {:.0}% of {} CPUs)", info,
+                one / cpus as f64 * 100f64,
+                five / cpus as f64 * 100f64,
+                fifteen / cpus as f64 * 100f64,
+                cpus,
+            ),
+        };
+
+        if style == DisplayStyle::Default {
+            return info;
+        }
+
+        // Write process and thread counts
+        let info = match self.pids_limit {
+            Some(limit) => format!("{}\n\t- {}: {} (limit {})", info, "Processes".yellow(), self.processes, limit),
+            None => format!("{}\n\t- {}: {}", info, "Processes".yellow(), self.processes),
+        };
+        let info = match self.threads {
+            Some(threads) => format!("{}\n\t- {}: {}", info, "Threads".yellow(), threads),
+            None => info,
+        };
+
+        info
+    }
+
+    fn get_json(&self) -> Value {
+        to_json("uptime", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::args::DisplayStyle;
+    use crate::system::component::Component;
+    use crate::system::uptime::{format_uptime, UptimeInfo};
+
+    #[test]
+    fn it_works() {
+        let uptime = UptimeInfo {
+            uptime: 3 * 86400 + 4 * 3600 + 12 * 60,
+            boot_time: 1748766600,
+            load_average: [0.52, 0.58, 0.59],
+            cpus: 4,
+            processes: 312,
+            threads: Some(1204),
+            pids_limit: Some(4096),
+        };
+
+        assert_eq!(format_uptime(uptime.uptime, 3), "3d 4h 12m");
+        assert_eq!(format_uptime(3600 + 5 * 60, 2), "1h 5m");
+        assert_eq!(format_uptime(30, 2), "0m");
+        assert!(uptime.get_info(DisplayStyle::Minimal).ends_with("3d 4h, load 0.52"));
+        assert!(uptime.get_info(DisplayStyle::Detailed).contains("312 (limit 4096)"));
+
+        println!("{}", uptime.get_info(DisplayStyle::Detailed));
+    }
+}
@@ -0,0 +1,156 @@
+use std::fs;
+use std::path::Path;
+use sysinfo::System;
+use crate::cli::args::Cli;
+use crate::system::battery::BatteryInfo;
+use crate::system::component::Component;
+use crate::system_info::grabber::info_grab::Grabber;
+use crate::utils::error::WMIError;
+use crate::utils::sysfs::{read_number, read_string};
+
+const POWER_SUPPLY_PATH: &str = "/sys/class/power_supply";
+
+pub struct BatteryGrabber;
+
+impl Grabber for BatteryGrabber {
+    fn grab(_sys: &System, _cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        // Machines without a battery simply get an empty list.
+        let battery_info = read_batteries(Path::new(POWER_SUPPLY_PATH))
+            .into_iter()
+            .map(|battery| Box::new(battery) as Box<dyn Component>)
+            .collect();
+
+        Ok(battery_info)
+    }
+}
+
+fn read_batteries(root: &Path) -> Vec<BatteryInfo> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+
+    let mut supplies = entries.flatten().map(|entry| entry.path()).collect::<Vec<_>>();
+    supplies.sort();
+
+    // AC adapters are reported as "Mains", USB-C chargers as "USB".
+    let ac_online = supplies.iter()
+        .filter(|supply| matches!(read_string(&supply.join("type")).as_deref(), Some("Mains") | Some("USB")))
+        .filter_map(|supply| read_number::<u8>(&supply.join("online")))
+        .fold(None, |online, value| Some(online.unwrap_or(false) || value == 1));
+
+    supplies.iter()
+        .filter(|supply| read_string(&supply.join("type")).as_deref() == Some("Battery"))
+        // Skip batteries of peripherals such as wireless mice.
+        .filter(|supply| read_string(&supply.join("scope")).as_deref() != Some("Device"))
+        .map(|supply| read_battery(supply, ac_online))
+        .collect()
+}
+
+fn read_battery(path: &Path, ac_online: Option<bool>) -> BatteryInfo {
+    let number = |name: &str| read_number::<u64>(&path.join(name));
+
+    // Drivers report either energy (µWh) with power (µW), or charge (µAh) with current (µA).
+    let (now, full, design, rate) = match number("energy_now") {
+        Some(energy) => (Some(energy), number("energy_full"), number("energy_full_design"), number("power_now")),
+        None => (number("charge_now"), number("charge_full"), number("charge_full_design"), number("current_now")),
+    };
+
+    let status = read_string(&path.join("status"));
+    let capacity = read_number::<u8>(&path.join("capacity")).or_else(|| match (now, full) {
+        (Some(now), Some(full)) if full > 0 => Some((now * 100 / full).min(100) as u8),
+        _ => None,
+    });
+
+    let time_remaining = match status.as_deref() {
+        Some("Discharging") => number("time_to_empty_now").or_else(|| match (now, rate) {
+            (Some(now), Some(rate)) if rate > 0 => Some(now * 3600 / rate),
+            _ => None,
+        }),
+        Some("Charging") => number("time_to_full_now").or_else(|| match (now, full, rate) {
+            (Some(now), Some(full), Some(rate)) if rate > 0 => Some(full.saturating_sub(now) * 3600 / rate),
+            _ => None,
+        }),
+        _ => None,
+    };
+
+    BatteryInfo {
+        name: path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default(),
+        manufacturer: read_string(&path.join("manufacturer")),
+        model: read_string(&path.join("model_name")),
+        capacity,
+        status,
+        time_remaining,
+        // Some firmwares report 0 when the counter is not supported.
+        cycle_count: read_number::<u32>(&path.join("cycle_count")).filter(|count| *count > 0),
+        full_capacity: full,
+        design_capacity: design,
+        ac_online,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::battery_grabber::read_batteries;
+    use crate::utils::sysfs::fake_tree;
+
+    #[test]
+    fn reads_energy_battery() {
+        let root = fake_tree("battery-energy", &[
+            ("AC/type", "Mains\n"),
+            ("AC/online", "0\n"),
+            ("BAT0/type", "Battery\n"),
+            ("BAT0/status", "Discharging\n"),
+            ("BAT0/capacity", "80\n"),
+            ("BAT0/energy_now", "40000000\n"),
+            ("BAT0/energy_full", "50000000\n"),
+            ("BAT0/energy_full_design", "57000000\n"),
+            ("BAT0/power_now", "10000000\n"),
+            ("BAT0/cycle_count", "312\n"),
+            ("BAT0/manufacturer", "SMP\n"),
+            ("BAT0/model_name", "5B10W13930\n"),
+            ("hid-mouse-battery/type", "Battery\n"),
+            ("hid-mouse-battery/scope", "Device\n"),
+        ]);
+
+        let batteries = read_batteries(&root);
+        assert_eq!(batteries.len(), 1);
+
+        let battery = &batteries[0];
+        assert_eq!(battery.name, "BAT0");
+        assert_eq!(battery.capacity, Some(80));
+        assert_eq!(battery.time_remaining, Some(4 * 3600));
+        assert_eq!(battery.cycle_count, Some(312));
+        assert_eq!(battery.ac_online, Some(false));
+        assert_eq!(battery.model.as_deref(), Some("5B10W13930"));
+        assert!((battery.health().unwrap() - 87.7).abs() < 0.1);
+    }
+
+    #[test]
+    fn reads_charge_battery() {
+        let root = fake_tree("battery-charge", &[
+            ("ADP1/type", "Mains\n"),
+            ("ADP1/online", "1\n"),
+            ("BAT1/type", "Battery\n"),
+            ("BAT1/status", "Charging\n"),
+            ("BAT1/charge_now", "2000000\n"),
+            ("BAT1/charge_full", "4000000\n"),
+            ("BAT1/current_now", "1000000\n"),
+            ("BAT1/cycle_count", "0\n"),
+        ]);
+
+        let batteries = read_batteries(&root);
+        let battery = &batteries[0];
+        assert_eq!(battery.capacity, Some(50));
+        assert_eq!(battery.time_remaining, Some(2 * 3600));
+        assert_eq!(battery.cycle_count, None);
+        assert_eq!(battery.ac_online, Some(true));
+        assert_eq!(battery.health(), None);
+    }
+
+    #[test]
+    fn no_battery() {
+        let root = fake_tree("battery-none", &[("AC/type", "Mains\n"), ("AC/online", "1\n")]);
+
+        assert!(read_batteries(&root).is_empty());
+    }
+}
@@ -0,0 +1,252 @@
+use std::path::Path;
+use sysinfo::System;
+use crate::cli::args::Cli;
+use crate::system::board::{BoardInfo, CloudInstance};
+use crate::system::component::Component;
+use crate::system_info::grabber::info_grab::Grabber;
+use crate::utils::error::WMIError;
+use crate::utils::sysfs::{read_number, read_string};
+
+pub const DMI_ID_PATH: &str = "/sys/class/dmi/id";
+
+// SMBIOS chassis types, starting at 0x01.
+const CHASSIS_TYPES: [&str; 36] = [
+    "Other", "Unknown", "Desktop", "Low Profile Desktop", "Pizza Box", "Mini Tower", "Tower", "Portable",
+    "Laptop", "Notebook", "Hand Held", "Docking Station", "All in One", "Sub Notebook", "Space-saving",
+    "Lunch Box", "Main Server Chassis", "Expansion Chassis", "SubChassis", "Bus Expansion Chassis",
+    "Peripheral Chassis", "RAID Chassis", "Rack Mount Chassis", "Sealed-case PC", "Multi-system chassis",
+    "Compact PCI", "Advanced TCA", "Blade", "Blade Enclosure", "Tablet", "Convertible", "Detachable",
+    "IoT Gateway", "Embedded PC", "Mini PC", "Stick PC",
+];
+
+// Asset tags set by cloud providers on their virtual chassis.
+const AZURE_ASSET_TAG: &str = "7783-7084-3265-9085-8269-3286-77";
+const ORACLE_ASSET_TAG: &str = "OracleCloud.com";
+
+// Vendors or products of hypervisors, which usually report an "Other" chassis.
+const VIRTUAL_MACHINES: [&str; 8] = ["QEMU", "KVM", "VMware", "VirtualBox", "innotek GmbH", "Xen", "Parallels", "Virtual Machine"];
+
+pub struct BoardGrabber;
+
+impl Grabber for BoardGrabber {
+    fn grab(_sys: &System, cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        let board_info = read_board(Path::new(DMI_ID_PATH), cli.show_serials)
+            .map(|board| Box::new(board) as Box<dyn Component>)
+            .into_iter()
+            .collect();
+
+        Ok(board_info)
+    }
+}
+
+/// Read /sys/class/dmi/id. Serial numbers and the UUID are left out unless `serials` is set.
+fn read_board(root: &Path, serials: bool) -> Option<BoardInfo> {
+    if !root.exists() {
+        return None;
+    }
+
+    let attribute = |name: &str| read_string(&root.join(name)).filter(|value| !is_placeholder(value));
+    let serial = |name: &str| if serials { attribute(name) } else { None };
+
+    let system_vendor = attribute("sys_vendor");
+    let product_name = attribute("product_name");
+    let chassis_number = read_number::<usize>(&root.join("chassis_type"));
+
+    Some(BoardInfo {
+        chassis: chassis_category(chassis_number, &[&system_vendor, &product_name]),
+        chassis_type: chassis_number.and_then(|number| CHASSIS_TYPES.get(number.checked_sub(1)?)).map(|name| name.to_string()),
+        system_vendor,
+        product_name,
+        product_version: attribute("product_version"),
+        board_vendor: attribute("board_vendor"),
+        board_name: attribute("board_name"),
+        board_version: attribute("board_version"),
+        bios_vendor: attribute("bios_vendor"),
+        bios_version: attribute("bios_version"),
+        bios_date: attribute("bios_date"),
+        bios_release: attribute("bios_release"),
+        product_serial: serial("product_serial"),
+        product_uuid: serial("product_uuid"),
+        board_serial: serial("board_serial"),
+        chassis_serial: serial("chassis_serial"),
+        cloud: cloud_instance(root),
+    })
+}
+
+/// Identify the cloud provider from DMI only, no metadata endpoint is contacted. The product UUID
+/// is read for this even without `--show-serials`, but never reported.
+fn cloud_instance(root: &Path) -> Option<CloudInstance> {
+    let attribute = |name: &str| read_string(&root.join(name)).unwrap_or_default();
+    let (system_vendor, product_name) = (attribute("sys_vendor"), attribute("product_name"));
+
+    // Nitro instances carry the instance ID as board asset tag, older Xen ones an "amazon" BIOS
+    // version. product_uuid is only readable by root, so it's the last resort.
+    let provider = if system_vendor == "Amazon EC2" || is_instance_id(&attribute("board_asset_tag"))
+        || (attribute("bios_vendor") == "Xen" && attribute("bios_version").contains("amazon"))
+        || attribute("product_uuid").to_lowercase().starts_with("ec2") {
+        "AWS"
+    } else if system_vendor == "Google" || product_name == "Google Compute Engine" {
+        "GCP"
+    } else if attribute("chassis_asset_tag") == AZURE_ASSET_TAG {
+        "Azure"
+    } else if attribute("chassis_asset_tag") == ORACLE_ASSET_TAG {
+        "Oracle Cloud"
+    } else if system_vendor == "DigitalOcean" {
+        "DigitalOcean"
+    } else if system_vendor == "Hetzner" {
+        "Hetzner"
+    } else {
+        return None;
+    };
+
+    // Nitro instances name their type as product, e.g. "m5.large", Xen ones report "HVM domU".
+    let instance_type = match provider {
+        "AWS" => Some(product_name).filter(|name| name.contains('.')),
+        _ => None,
+    };
+
+    Some(CloudInstance {
+        provider: provider.to_string(),
+        instance_family: instance_type.as_ref().and_then(|name| name.split_once('.')).map(|(family, _)| family.to_string()),
+        instance_type,
+    })
+}
+
+/// EC2 instance IDs, e.g. "i-0123456789abcdef0", have 8 hexadecimal digits in older accounts and 17 since 2016.
+fn is_instance_id(value: &str) -> bool {
+    value.strip_prefix("i-")
+        .is_some_and(|id| matches!(id.len(), 8 | 17) && id.chars().all(|digit| matches!(digit, '0'..='9' | 'a'..='f')))
+}
+
+/// Firmware vendors leave unset fields with placeholders such as "To Be Filled By O.E.M.".
+fn is_placeholder(value: &str) -> bool {
+    matches!(
+        value.to_lowercase().as_str(),
+        "to be filled by o.e.m." | "default string" | "not specified" | "not defined" | "not applicable" | "system product name"
+            | "system manufacturer" | "system version" | "system serial number" | "none" | "n/a" | "0" | "x.x"
+    )
+}
+
+fn chassis_category(chassis_type: Option<usize>, system: &[&Option<String>]) -> Option<String> {
+    let virtual_machine = system.iter()
+        .filter_map(|value| value.as_deref())
+        .any(|value| VIRTUAL_MACHINES.iter().any(|name| value.contains(name)));
+    if virtual_machine {
+        return Some("Virtual machine".to_string());
+    }
+
+    let category = match chassis_type? {
+        8..=10 | 14 | 30..=32 => "Laptop",
+        3..=7 | 13 | 15 | 16 | 24 | 35 | 36 => "Desktop",
+        17 | 23 | 25 | 28 | 29 => "Server",
+        11 => "Handheld",
+        33 | 34 => "Embedded",
+        _ => return None,
+    };
+
+    Some(category.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::board_grabber::{cloud_instance, read_board};
+    use crate::utils::sysfs::fake_tree;
+
+    #[test]
+    fn reads_laptop() {
+        let root = fake_tree("board-laptop", &[
+            ("sys_vendor", "LENOVO\n"),
+            ("product_name", "21HM\n"),
+            ("product_version", "ThinkPad X1 Carbon Gen 11\n"),
+            ("product_serial", "PF4ABCDE\n"),
+            ("product_uuid", "4c4c4544-0042-3510-8051-b4c04f4e4d32\n"),
+            ("board_vendor", "LENOVO\n"),
+            ("board_name", "21HMCTO1WW\n"),
+            ("board_version", "Not Defined\n"),
+            ("bios_vendor", "LENOVO\n"),
+            ("bios_version", "N3XET53W (1.28 )\n"),
+            ("bios_date", "08/21/2024\n"),
+            ("bios_release", "1.28\n"),
+            ("chassis_type", "10\n"),
+            ("chassis_serial", "To Be Filled By O.E.M.\n"),
+        ]);
+
+        let board = read_board(&root, false).unwrap();
+        assert_eq!(board.product_name.as_deref(), Some("21HM"));
+        assert_eq!(board.bios_version.as_deref(), Some("N3XET53W (1.28 )"));
+        assert_eq!(board.chassis_type.as_deref(), Some("Notebook"));
+        assert_eq!(board.chassis.as_deref(), Some("Laptop"));
+        assert_eq!(board.product_serial, None);
+        assert_eq!(board.product_uuid, None);
+
+        let board = read_board(&root, true).unwrap();
+        assert_eq!(board.product_serial.as_deref(), Some("PF4ABCDE"));
+        assert_eq!(board.product_uuid.as_deref(), Some("4c4c4544-0042-3510-8051-b4c04f4e4d32"));
+        assert_eq!(board.chassis_serial, None);
+    }
+
+    #[test]
+    fn reads_virtual_machine() {
+        let root = fake_tree("board-vm", &[
+            ("sys_vendor", "QEMU\n"),
+            ("product_name", "Standard PC (Q35 + ICH9, 2009)\n"),
+            ("bios_vendor", "SeaBIOS\n"),
+            ("chassis_type", "1\n"),
+            ("board_name", "Default string\n"),
+        ]);
+
+        let board = read_board(&root, false).unwrap();
+        assert_eq!(board.chassis.as_deref(), Some("Virtual machine"));
+        assert_eq!(board.chassis_type.as_deref(), Some("Other"));
+        assert_eq!(board.board_name, None);
+
+        assert!(read_board(&root.join("missing"), false).is_none());
+    }
+
+    #[test]
+    fn identifies_cloud_instances() {
+        let aws = fake_tree("board-aws", &[
+            ("sys_vendor", "Amazon EC2\n"),
+            ("product_name", "c7gn.2xlarge\n"),
+            ("board_asset_tag", "i-0123456789abcdef0\n"),
+        ]);
+        let cloud = cloud_instance(&aws).unwrap();
+        assert_eq!(cloud.provider, "AWS");
+        assert_eq!(cloud.instance_type.as_deref(), Some("c7gn.2xlarge"));
+        assert_eq!(cloud.instance_family.as_deref(), Some("c7gn"));
+
+        let aws_xen = fake_tree("board-aws-xen", &[
+            ("sys_vendor", "Xen\n"),
+            ("product_name", "HVM domU\n"),
+            ("bios_vendor", "Xen\n"),
+            ("bios_version", "4.11.amazon\n"),
+        ]);
+        let cloud = cloud_instance(&aws_xen).unwrap();
+        assert_eq!(cloud.provider, "AWS");
+        assert_eq!(cloud.instance_type, None);
+
+        let provider = |name: &str, files: &[(&str, &str)]| cloud_instance(&fake_tree(name, files)).map(|cloud| cloud.provider);
+        // Only the asset tag gives the instance away, e.g. bare metal instances.
+        assert_eq!(provider("board-aws-asset-tag", &[
+            ("sys_vendor", "Intel Corporation\n"),
+            ("product_name", "S2600WF\n"),
+            ("board_asset_tag", "i-0fedcba9876543210\n"),
+        ]).as_deref(), Some("AWS"));
+        assert_eq!(provider("board-aws-short-id", &[("board_asset_tag", "i-0a1b2c3d\n")]).as_deref(), Some("AWS"));
+        assert_eq!(provider("board-asset-tag-not-aws", &[("sys_vendor", "Dell Inc.\n"), ("board_asset_tag", "i-Inventory 42\n")]), None);
+        assert_eq!(provider("board-aws-uuid", &[
+            ("sys_vendor", "Xen\n"),
+            ("product_uuid", "EC2E1916-9099-7CAF-FD21-012345ABCDEF\n"),
+        ]).as_deref(), Some("AWS"));
+        assert_eq!(provider("board-gcp", &[("sys_vendor", "Google\n"), ("product_name", "Google Compute Engine\n")]).as_deref(), Some("GCP"));
+        assert_eq!(provider("board-azure", &[
+            ("sys_vendor", "Microsoft Corporation\n"),
+            ("product_name", "Virtual Machine\n"),
+            ("chassis_asset_tag", "7783-7084-3265-9085-8269-3286-77\n"),
+        ]).as_deref(), Some("Azure"));
+        assert_eq!(provider("board-oracle", &[("sys_vendor", "QEMU\n"), ("chassis_asset_tag", "OracleCloud.com\n")]).as_deref(), Some("Oracle Cloud"));
+        assert_eq!(provider("board-digitalocean", &[("sys_vendor", "DigitalOcean\n"), ("product_name", "Droplet\n")]).as_deref(), Some("DigitalOcean"));
+        assert_eq!(provider("board-hetzner", &[("sys_vendor", "Hetzner\n"), ("product_name", "vServer\n")]).as_deref(), Some("Hetzner"));
+        assert_eq!(provider("board-cloud-none", &[("sys_vendor", "QEMU\n")]), None);
+    }
+}
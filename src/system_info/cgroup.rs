@@ -0,0 +1,199 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::system_info::mountinfo::{parse_mountinfo, MOUNTINFO_PATH};
+use crate::utils::sysfs::{parse_cpu_list, read_number, read_string};
+
+pub const CGROUP_PATH: &str = "/proc/self/cgroup";
+
+// cgroup v1 reports an unlimited memory limit as the largest page aligned 64-bit value.
+const V1_UNLIMITED: u64 = 1 << 62;
+
+/// Resource limits applying to the current process, through its cgroup and every ancestor.
+#[derive(Debug, Default)]
+pub struct Limits {
+    pub memory_limit: Option<u64>,      // Byte
+    pub memory_usage: Option<u64>,      // Byte, without inactive page cache
+    pub cpu_quota: Option<f64>,         // CPUs worth of time per period
+    pub cpuset: Option<Vec<usize>>,
+    pub pids_limit: Option<u64>,
+}
+
+/// Limits of the current process, read from the live /proc and cgroup filesystems.
+pub fn current() -> Limits {
+    match (fs::read_to_string(CGROUP_PATH), fs::read_to_string(MOUNTINFO_PATH)) {
+        (Ok(cgroup), Ok(mountinfo)) => read_limits(&cgroup, &mountinfo, Path::new("/")),
+        _ => Limits::default(),
+    }
+}
+
+/// Resolve the cgroup directories of a process from its /proc/<pid>/cgroup and mountinfo, with
+/// mount points taken relative to `root`, then read the v2 limit, falling back to the v1 one.
+pub fn read_limits(cgroup: &str, mountinfo: &str, root: &Path) -> Limits {
+    let mounts = parse_mountinfo(mountinfo);
+    // Lines are "hierarchy-ID:controller-list:cgroup-path", with an empty list for v2.
+    let groups = cgroup.lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, ':');
+            let (_, controllers, path) = (fields.next()?, fields.next()?, fields.next()?);
+            Some((controllers.split(',').filter(|controller| !controller.is_empty()).collect::<Vec<_>>(), path))
+        })
+        .collect::<Vec<_>>();
+
+    // Directories from the process cgroup up to the root of the hierarchy.
+    let hierarchy = |controller: Option<&str>| -> Vec<PathBuf> {
+        let group = groups.iter().find(|(controllers, _)| match controller {
+            Some(controller) => controllers.contains(&controller),
+            None => controllers.is_empty(),
+        });
+        let mount = mounts.iter().find(|mount| match controller {
+            Some(controller) => mount.fs_type == "cgroup" && mount.super_options.iter().any(|option| option == controller),
+            None => mount.fs_type == "cgroup2",
+        });
+        let (Some((_, path)), Some(mount)) = (group, mount) else {
+            return Vec::new();
+        };
+
+        // Inside a cgroup namespace or a container the mount root may be the group itself.
+        let relative = path.strip_prefix(mount.root.as_str()).unwrap_or(path);
+        let top = root.join(mount.mount_point.trim_start_matches('/'));
+        let leaf = top.join(relative.trim_start_matches('/'));
+
+        leaf.ancestors().take_while(|directory| directory.starts_with(&top)).map(|directory| directory.to_path_buf()).collect()
+    };
+
+    let unified = hierarchy(None);
+    let (memory, cpu, cpuset, pids) = (hierarchy(Some("memory")), hierarchy(Some("cpu")), hierarchy(Some("cpuset")), hierarchy(Some("pids")));
+
+    // v2 writes "max" when unlimited, which doesn't parse.
+    let memory_limit = lowest(&unified, |group| read_number::<u64>(&group.join("memory.max")))
+        .or_else(|| lowest(&memory, |group| read_number::<u64>(&group.join("memory.limit_in_bytes")).filter(|limit| *limit < V1_UNLIMITED)));
+    let memory_usage = match unified.first().filter(|group| group.join("memory.current").exists()) {
+        Some(group) => usage(group, "memory.current", "inactive_file"),
+        None => memory.first().and_then(|group| usage(group, "memory.usage_in_bytes", "total_inactive_file")),
+    };
+
+    let cpu_quota = lowest(&unified, |group| {
+        let max = read_string(&group.join("cpu.max"))?;
+        let (quota, period) = max.split_once(' ')?;
+        Some(quota.parse::<f64>().ok()? / period.parse::<f64>().ok()?)
+    }).or_else(|| lowest(&cpu, |group| {
+        // A quota of -1 means no limit.
+        let quota = read_number::<f64>(&group.join("cpu.cfs_quota_us")).filter(|quota| *quota > 0f64)?;
+        Some(quota / read_number::<f64>(&group.join("cpu.cfs_period_us"))?)
+    }));
+
+    let cpuset = unified.first().and_then(|group| read_string(&group.join("cpuset.cpus.effective")))
+        .or_else(|| cpuset.first().and_then(|group| read_string(&group.join("cpuset.effective_cpus")).or_else(|| read_string(&group.join("cpuset.cpus")))))
+        .map(|cpus| parse_cpu_list(&cpus));
+
+    let pids_limit = lowest(&unified, |group| read_number::<u64>(&group.join("pids.max")))
+        .or_else(|| lowest(&pids, |group| read_number::<u64>(&group.join("pids.max"))));
+
+    Limits { memory_limit, memory_usage, cpu_quota, cpuset, pids_limit }
+}
+
+/// The tightest limit set along the hierarchy.
+fn lowest<T: PartialOrd>(hierarchy: &[PathBuf], limit: impl Fn(&Path) -> Option<T>) -> Option<T> {
+    hierarchy.iter()
+        .filter_map(|group| limit(group))
+        .fold(None, |lowest, limit| match lowest {
+            Some(lowest) if lowest <= limit => Some(lowest),
+            _ => Some(limit),
+        })
+}
+
+/// Memory usage the way container runtimes report it, leaving out reclaimable page cache.
+fn usage(group: &Path, file: &str, inactive_key: &str) -> Option<u64> {
+    let usage = read_string(&group.join(file))?.parse::<u64>().ok()?;
+    let inactive = read_string(&group.join("memory.stat")).unwrap_or_default()
+        .lines()
+        .find_map(|line| line.strip_prefix(inactive_key)?.strip_prefix(' ')?.parse::<u64>().ok())
+        .unwrap_or(0);
+
+    Some(usage.saturating_sub(inactive))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::cgroup::read_limits;
+    use crate::utils::sysfs::fake_tree;
+
+    #[test]
+    fn reads_v2_limits() {
+        // A Kubernetes container without its own memory limit, bound by the one of its pod.
+        let root = fake_tree("cgroup-v2", &[
+            ("sys/fs/cgroup/kubepods/memory.max", "8589934592\n"),
+            ("sys/fs/cgroup/kubepods/pod1/memory.max", "4294967296\n"),
+            ("sys/fs/cgroup/kubepods/pod1/cpu.max", "max 100000\n"),
+            ("sys/fs/cgroup/kubepods/pod1/app/memory.max", "max\n"),
+            ("sys/fs/cgroup/kubepods/pod1/app/memory.current", "1395864371\n"),
+            ("sys/fs/cgroup/kubepods/pod1/app/memory.stat", "anon 1073741824\ninactive_file 107374182\nactive_file 0\n"),
+            ("sys/fs/cgroup/kubepods/pod1/app/cpu.max", "150000 100000\n"),
+            ("sys/fs/cgroup/kubepods/pod1/app/cpuset.cpus.effective", "0-3\n"),
+            ("sys/fs/cgroup/kubepods/pod1/app/pids.max", "1024\n"),
+        ]);
+        let mountinfo = "25 1 0:22 / /sys/fs/cgroup rw,nosuid,nodev,noexec,relatime shared:4 - cgroup2 cgroup2 rw,nsdelegate\n";
+
+        let limits = read_limits("0::/kubepods/pod1/app\n", mountinfo, &root);
+        assert_eq!(limits.memory_limit, Some(4294967296));
+        assert_eq!(limits.memory_usage, Some(1395864371 - 107374182));
+        assert_eq!(limits.cpu_quota, Some(1.5));
+        assert_eq!(limits.cpuset, Some(vec![0, 1, 2, 3]));
+        assert_eq!(limits.pids_limit, Some(1024));
+    }
+
+    #[test]
+    fn reads_v2_namespaced_container() {
+        // Docker with a cgroup namespace: the group is the root of its own mount.
+        let root = fake_tree("cgroup-v2-namespace", &[
+            ("sys/fs/cgroup/memory.max", "2147483648\n"),
+            ("sys/fs/cgroup/pids.max", "max\n"),
+        ]);
+        let mountinfo = "702 690 0:26 / /sys/fs/cgroup ro,nosuid,nodev,noexec,relatime - cgroup2 cgroup rw,nsdelegate\n";
+
+        let limits = read_limits("0::/\n", mountinfo, &root);
+        assert_eq!(limits.memory_limit, Some(2147483648));
+        assert_eq!(limits.pids_limit, None);
+        assert_eq!(limits.cpu_quota, None);
+    }
+
+    #[test]
+    fn reads_v1_limits() {
+        let root = fake_tree("cgroup-v1", &[
+            ("sys/fs/cgroup/memory/docker/abc/memory.limit_in_bytes", "1073741824\n"),
+            ("sys/fs/cgroup/memory/docker/abc/memory.usage_in_bytes", "536870912\n"),
+            ("sys/fs/cgroup/memory/docker/abc/memory.stat", "cache 0\ntotal_inactive_file 268435456\n"),
+            ("sys/fs/cgroup/memory/docker/memory.limit_in_bytes", "9223372036854771712\n"),
+            ("sys/fs/cgroup/cpu,cpuacct/docker/abc/cpu.cfs_quota_us", "200000\n"),
+            ("sys/fs/cgroup/cpu,cpuacct/docker/abc/cpu.cfs_period_us", "100000\n"),
+            ("sys/fs/cgroup/cpuset/docker/abc/cpuset.cpus", "2,4-5\n"),
+            ("sys/fs/cgroup/pids/docker/abc/pids.max", "max\n"),
+            ("sys/fs/cgroup/unified/cgroup.procs", ""),
+        ]);
+        let mountinfo = "\
+33 32 0:29 / /sys/fs/cgroup/cpu,cpuacct rw,relatime - cgroup cgroup rw,cpu,cpuacct
+35 32 0:31 / /sys/fs/cgroup/cpuset rw,relatime - cgroup cgroup rw,cpuset
+36 32 0:32 / /sys/fs/cgroup/memory rw,relatime - cgroup cgroup rw,memory
+40 32 0:36 / /sys/fs/cgroup/pids rw,relatime - cgroup cgroup rw,pids
+42 32 0:38 / /sys/fs/cgroup/unified rw,relatime - cgroup2 cgroup2 rw
+";
+        let cgroup = "8:pids:/docker/abc\n4:memory:/docker/abc\n3:cpuset:/docker/abc\n2:cpu,cpuacct:/docker/abc\n0::/docker/abc\n";
+
+        let limits = read_limits(cgroup, mountinfo, &root);
+        assert_eq!(limits.memory_limit, Some(1073741824));
+        assert_eq!(limits.memory_usage, Some(268435456));
+        assert_eq!(limits.cpu_quota, Some(2.0));
+        assert_eq!(limits.cpuset, Some(vec![2, 4, 5]));
+        assert_eq!(limits.pids_limit, None);
+    }
+
+    #[test]
+    fn no_limits() {
+        let root = fake_tree("cgroup-none", &[("sys/fs/cgroup/memory.max", "max\n")]);
+        let mountinfo = "25 1 0:22 / /sys/fs/cgroup rw - cgroup2 cgroup2 rw\n";
+
+        let limits = read_limits("0::/\n", mountinfo, &root);
+        assert_eq!(limits.memory_limit, None);
+        assert_eq!(limits.cpuset, None);
+    }
+}
@@ -0,0 +1,94 @@
+use std::fs;
+use std::path::Path;
+use crate::system::cpu::FrequencyPolicy;
+use crate::utils::sysfs::{parse_cpu_list, read_number, read_string};
+
+pub const CPUFREQ_PATH: &str = "/sys/devices/system/cpu/cpufreq";
+pub const INTEL_PSTATE_PATH: &str = "/sys/devices/system/cpu/intel_pstate";
+pub const PLATFORM_PROFILE_PATH: &str = "/sys/firmware/acpi/platform_profile";
+
+/// Read every cpufreq policy, sorted by policy number. Frequencies are converted from kHz to MHz.
+pub fn read_policies(cpufreq: &Path, intel_pstate: &Path) -> Vec<FrequencyPolicy> {
+    let Ok(entries) = fs::read_dir(cpufreq) else {
+        return Vec::new();
+    };
+
+    // Boost is global on acpi-cpufreq and amd-pstate, intel_pstate inverts it as no_turbo.
+    let global_boost = read_number::<u8>(&cpufreq.join("boost")).map(|boost| boost == 1)
+        .or_else(|| read_number::<u8>(&intel_pstate.join("no_turbo")).map(|no_turbo| no_turbo == 0));
+
+    let mut policies = entries.flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let number = name.strip_prefix("policy")?.parse::<usize>().ok()?;
+            let policy = entry.path();
+            let megahertz = |file: &str| read_number::<u64>(&policy.join(file)).map(|frequency| frequency / 1000);
+
+            Some((number, FrequencyPolicy {
+                cpus: parse_cpu_list(&read_string(&policy.join("affected_cpus")).or_else(|| read_string(&policy.join("related_cpus")))?),
+                driver: read_string(&policy.join("scaling_driver")),
+                governor: read_string(&policy.join("scaling_governor")),
+                min_frequency: megahertz("scaling_min_freq"),
+                max_frequency: megahertz("scaling_max_freq"),
+                hardware_max_frequency: megahertz("cpuinfo_max_freq"),
+                // intel_pstate exposes the base frequency, amd-pstate the nominal one.
+                base_frequency: megahertz("base_frequency").or_else(|| megahertz("amd_pstate_nominal_freq")),
+                energy_performance_preference: read_string(&policy.join("energy_performance_preference")),
+                boost: read_number::<u8>(&policy.join("boost")).map(|boost| boost == 1).or(global_boost),
+            }))
+        })
+        .collect::<Vec<_>>();
+    policies.sort_by_key(|(number, _)| *number);
+
+    policies.into_iter().map(|(_, policy)| policy).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::cpufreq::read_policies;
+    use crate::utils::sysfs::fake_tree;
+
+    #[test]
+    fn reads_intel_pstate() {
+        let mut files = Vec::new();
+        for (policy, max) in [("policy0", "5000000\n"), ("policy1", "5000000\n"), ("policy10", "3800000\n")] {
+            let cpu = policy.trim_start_matches("policy");
+            files.extend([
+                (format!("cpufreq/{}/affected_cpus", policy), format!("{}\n", cpu)),
+                (format!("cpufreq/{}/scaling_driver", policy), "intel_pstate\n".to_string()),
+                (format!("cpufreq/{}/scaling_governor", policy), "powersave\n".to_string()),
+                (format!("cpufreq/{}/scaling_min_freq", policy), "800000\n".to_string()),
+                (format!("cpufreq/{}/scaling_max_freq", policy), max.to_string()),
+                (format!("cpufreq/{}/cpuinfo_max_freq", policy), max.to_string()),
+                (format!("cpufreq/{}/base_frequency", policy), "2100000\n".to_string()),
+                (format!("cpufreq/{}/energy_performance_preference", policy), "balance_performance\n".to_string()),
+            ]);
+        }
+        files.push(("intel_pstate/no_turbo".to_string(), "0\n".to_string()));
+        let root = fake_tree("cpufreq-intel", &files.iter().map(|(path, content)| (path.as_str(), content.as_str())).collect::<Vec<_>>());
+
+        let policies = read_policies(&root.join("cpufreq"), &root.join("intel_pstate"));
+        assert_eq!(policies.iter().map(|policy| policy.cpus.clone()).collect::<Vec<_>>(), vec![vec![0], vec![1], vec![10]]);
+        assert_eq!(policies[0].driver.as_deref(), Some("intel_pstate"));
+        assert_eq!(policies[0].min_frequency, Some(800));
+        assert_eq!(policies[2].max_frequency, Some(3800));
+        assert_eq!(policies[0].base_frequency, Some(2100));
+        assert_eq!(policies[0].boost, Some(true));
+        assert_eq!(policies[1].energy_performance_preference.as_deref(), Some("balance_performance"));
+    }
+
+    #[test]
+    fn reads_acpi_cpufreq() {
+        let root = fake_tree("cpufreq-acpi", &[
+            ("cpufreq/boost", "0\n"),
+            ("cpufreq/policy0/affected_cpus", "0 1 2 3\n"),
+            ("cpufreq/policy0/scaling_driver", "acpi-cpufreq\n"),
+            ("cpufreq/policy0/scaling_governor", "schedutil\n"),
+        ]);
+
+        let policies = read_policies(&root.join("cpufreq"), &root.join("intel_pstate"));
+        assert_eq!(policies.len(), 1);
+        assert_eq!(policies[0].boost, Some(false));
+        assert_eq!(policies[0].max_frequency, None);
+    }
+}
@@ -0,0 +1,380 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use crate::system::cpu::{CpuCache, Vulnerability};
+use crate::utils::sysfs::read_string;
+
+pub const CPUINFO_PATH: &str = "/proc/cpuinfo";
+pub const CACHE_PATH: &str = "/sys/devices/system/cpu/cpu0/cache";
+pub const VULNERABILITIES_PATH: &str = "/sys/devices/system/cpu/vulnerabilities";
+
+// Instruction set extensions worth reporting, by /proc/cpuinfo flag name.
+const X86_EXTENSIONS: [(&str, &str); 20] = [
+    ("sse4_2", "SSE4.2"),
+    ("avx", "AVX"),
+    ("avx2", "AVX2"),
+    ("fma", "FMA"),
+    ("f16c", "F16C"),
+    ("bmi1", "BMI1"),
+    ("bmi2", "BMI2"),
+    ("aes", "AES-NI"),
+    ("vaes", "VAES"),
+    ("pclmulqdq", "PCLMULQDQ"),
+    ("sha_ni", "SHA"),
+    ("avx512f", "AVX-512F"),
+    ("avx512cd", "AVX-512CD"),
+    ("avx512bw", "AVX-512BW"),
+    ("avx512dq", "AVX-512DQ"),
+    ("avx512vl", "AVX-512VL"),
+    ("avx512ifma", "AVX-512IFMA"),
+    ("avx512vbmi", "AVX-512VBMI"),
+    ("avx512_vnni", "AVX-512VNNI"),
+    ("avx512_bf16", "AVX-512BF16"),
+];
+const ARM_EXTENSIONS: [(&str, &str); 9] = [
+    ("asimd", "NEON"),
+    ("asimddp", "DotProd"),
+    ("sve", "SVE"),
+    ("sve2", "SVE2"),
+    ("aes", "AES"),
+    ("sha2", "SHA2"),
+    ("sha3", "SHA3"),
+    ("atomics", "LSE"),
+    ("crc32", "CRC32"),
+];
+
+// Flags required by each x86-64 microarchitecture level of the psABI, cumulatively. OSXSAVE is left
+// out of v3: the kernel doesn't list it, but only lists avx once it enabled XSAVE, as std checks too.
+const X86_64_LEVELS: [&[&str]; 4] = [
+    &["lm", "cmov", "cx8", "fpu", "fxsr", "mmx", "syscall", "sse", "sse2"],
+    &["cx16", "lahf_lm", "popcnt", "pni", "sse4_1", "sse4_2", "ssse3"],
+    &["avx", "avx2", "bmi1", "bmi2", "f16c", "fma", "abm", "movbe"],
+    &["avx512f", "avx512bw", "avx512cd", "avx512dq", "avx512vl"],
+];
+
+// ARM implementer codes of the MIDR register.
+const ARM_IMPLEMENTERS: [(&str, &str); 8] = [
+    ("0x41", "ARM"),
+    ("0x42", "Broadcom"),
+    ("0x46", "Fujitsu"),
+    ("0x48", "HiSilicon"),
+    ("0x4e", "NVIDIA"),
+    ("0x51", "Qualcomm"),
+    ("0x61", "Apple"),
+    ("0xc0", "Ampere"),
+];
+
+/// Identification of the first processor, with flags named as in /proc/cpuinfo.
+#[derive(Debug, Default)]
+pub struct Identification {
+    pub vendor: Option<String>,
+    pub family: Option<u32>,
+    pub model: Option<u32>,
+    pub stepping: Option<u32>,
+    pub microcode: Option<String>,
+    pub flags: BTreeSet<String>,
+}
+
+impl Identification {
+    pub fn extensions(&self) -> Vec<String> {
+        let table = match self.flags.contains("asimd") {
+            true => &ARM_EXTENSIONS[..],
+            false => &X86_EXTENSIONS[..],
+        };
+
+        table.iter()
+            .filter(|(flag, _)| self.flags.contains(*flag))
+            .map(|(_, name)| name.to_string())
+            .collect()
+    }
+
+    /// Highest x86-64 level (1 to 4) whose flags, and those of every level below, are all present.
+    pub fn x86_64_level(&self) -> Option<u8> {
+        let level = X86_64_LEVELS.iter()
+            .take_while(|flags| flags.iter().all(|flag| self.flags.contains(*flag)))
+            .count();
+
+        (level > 0).then_some(level as u8)
+    }
+}
+
+/// Identify the CPU with CPUID on x86, and from /proc/cpuinfo elsewhere. The microcode
+/// revision is only known to the kernel.
+pub fn identify(cpuinfo: &str) -> Identification {
+    let parsed = parse_cpuinfo(cpuinfo);
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    return Identification {
+        microcode: parsed.microcode,
+        ..cpuid()
+    };
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    parsed
+}
+
+/// Parse the first processor of /proc/cpuinfo. x86 and ARM name their fields differently.
+pub fn parse_cpuinfo(content: &str) -> Identification {
+    let fields = content.split("\n\n")
+        .next()
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .collect::<Vec<_>>();
+    let field = |names: &[&str]| fields.iter().find(|(key, _)| names.contains(key)).map(|(_, value)| *value);
+    let number = |names: &[&str]| field(names).and_then(|value| match value.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => value.parse().ok(),
+    });
+
+    let vendor = field(&["vendor_id"]).map(|vendor| vendor.to_string()).or_else(|| {
+        let implementer = field(&["CPU implementer"])?;
+        ARM_IMPLEMENTERS.iter()
+            .find(|(code, _)| *code == implementer)
+            .map(|(_, name)| name.to_string())
+            .or_else(|| Some(implementer.to_string()))
+    });
+
+    Identification {
+        vendor,
+        family: number(&["cpu family", "CPU architecture"]),
+        model: number(&["model", "CPU part"]),
+        stepping: number(&["stepping", "CPU revision"]),
+        microcode: field(&["microcode"]).map(|microcode| microcode.to_string()),
+        flags: field(&["flags", "Features"]).unwrap_or_default()
+            .split_whitespace()
+            .map(|flag| flag.to_string())
+            .collect(),
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn cpuid() -> Identification {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::__cpuid;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::__cpuid;
+
+    // __cpuid is only safe to call since Rust 1.87.
+    #[allow(unused_unsafe)]
+    let (vendor, signature, extended) = unsafe { (__cpuid(0), __cpuid(1), __cpuid(0x8000_0001)) };
+
+    let vendor = [vendor.ebx, vendor.edx, vendor.ecx].iter().flat_map(|register| register.to_le_bytes()).collect::<Vec<_>>();
+
+    // Extended family and model only apply to families 6 and 15.
+    let base_family = (signature.eax >> 8) & 0xF;
+    let family = match base_family {
+        0xF => base_family + ((signature.eax >> 20) & 0xFF),
+        _ => base_family,
+    };
+    let model = match base_family {
+        0x6 | 0xF => (signature.eax >> 4) & 0xF | ((signature.eax >> 16) & 0xF) << 4,
+        _ => (signature.eax >> 4) & 0xF,
+    };
+
+    // std checks that the OS saves the AVX and AVX-512 registers, as the kernel does for its flags.
+    let detected = [
+        ("mmx", is_x86_feature_detected!("mmx")),
+        ("fxsr", is_x86_feature_detected!("fxsr")),
+        ("sse", is_x86_feature_detected!("sse")),
+        ("sse2", is_x86_feature_detected!("sse2")),
+        ("pni", is_x86_feature_detected!("sse3")),
+        ("ssse3", is_x86_feature_detected!("ssse3")),
+        ("sse4_1", is_x86_feature_detected!("sse4.1")),
+        ("sse4_2", is_x86_feature_detected!("sse4.2")),
+        ("cx16", is_x86_feature_detected!("cmpxchg16b")),
+        ("popcnt", is_x86_feature_detected!("popcnt")),
+        ("syscall", extended.edx & (1 << 11) != 0),
+        ("lahf_lm", extended.ecx & 1 != 0),
+        ("avx", is_x86_feature_detected!("avx")),
+        ("avx2", is_x86_feature_detected!("avx2")),
+        ("fma", is_x86_feature_detected!("fma")),
+        ("f16c", is_x86_feature_detected!("f16c")),
+        ("bmi1", is_x86_feature_detected!("bmi1")),
+        ("bmi2", is_x86_feature_detected!("bmi2")),
+        ("abm", is_x86_feature_detected!("lzcnt")),
+        ("movbe", is_x86_feature_detected!("movbe")),
+        ("xsave", is_x86_feature_detected!("xsave")),
+        ("aes", is_x86_feature_detected!("aes")),
+        ("vaes", is_x86_feature_detected!("vaes")),
+        ("pclmulqdq", is_x86_feature_detected!("pclmulqdq")),
+        ("sha_ni", is_x86_feature_detected!("sha")),
+        ("avx512f", is_x86_feature_detected!("avx512f")),
+        ("avx512cd", is_x86_feature_detected!("avx512cd")),
+        ("avx512bw", is_x86_feature_detected!("avx512bw")),
+        ("avx512dq", is_x86_feature_detected!("avx512dq")),
+        ("avx512vl", is_x86_feature_detected!("avx512vl")),
+        ("avx512ifma", is_x86_feature_detected!("avx512ifma")),
+        ("avx512vbmi", is_x86_feature_detected!("avx512vbmi")),
+        ("avx512_vnni", is_x86_feature_detected!("avx512vnni")),
+        ("avx512_bf16", is_x86_feature_detected!("avx512bf16")),
+    ];
+
+    // Long mode, CMOV, CMPXCHG8B and x87 are architectural on x86-64, but not on 32-bit x86.
+    let architectural = [
+        ("lm", extended.edx & (1 << 29) != 0),
+        ("cmov", signature.edx & (1 << 15) != 0),
+        ("cx8", signature.edx & (1 << 8) != 0),
+        ("fpu", signature.edx & 1 != 0),
+    ];
+    let flags = architectural.iter().chain(&detected)
+        .filter(|(_, present)| *present)
+        .map(|(flag, _)| flag.to_string())
+        .collect();
+
+    Identification {
+        vendor: Some(String::from_utf8_lossy(&vendor).to_string()),
+        family: Some(family),
+        model: Some(model),
+        stepping: Some(signature.eax & 0xF),
+        microcode: None,
+        flags,
+    }
+}
+
+/// Read the caches of a CPU, e.g. /sys/devices/system/cpu/cpu0/cache, sorted by level.
+pub fn read_caches(root: &Path) -> Vec<CpuCache> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+
+    let mut caches = entries.flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("index"))
+        .filter_map(|entry| {
+            let index = entry.path();
+            let size = read_string(&index.join("size"))?;
+            // Sizes are given as e.g. "48K" or "30720K".
+            let size = match size.strip_suffix('K') {
+                Some(size) => size.parse::<u64>().ok()? * 1024,
+                None => match size.strip_suffix('M') {
+                    Some(size) => size.parse::<u64>().ok()? * 1024 * 1024,
+                    None => size.parse().ok()?,
+                },
+            };
+
+            Some(CpuCache {
+                level: read_string(&index.join("level"))?.parse().ok()?,
+                kind: read_string(&index.join("type")).unwrap_or_else(|| "Unified".to_string()),
+                size,
+            })
+        })
+        .collect::<Vec<_>>();
+    caches.sort_by(|a, b| (a.level, &a.kind).cmp(&(b.level, &b.kind)));
+
+    caches
+}
+
+/// Read every entry of the vulnerabilities directory, sorted by name. Kernels before 4.15 don't
+/// have it and yield an empty list.
+pub fn read_vulnerabilities(root: &Path) -> Vec<Vulnerability> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+
+    let mut vulnerabilities = entries.flatten()
+        .filter_map(|entry| {
+            let detail = read_string(&entry.path())?;
+            Some(Vulnerability::new(entry.file_name().to_string_lossy().to_string(), detail))
+        })
+        .collect::<Vec<_>>();
+    vulnerabilities.sort_by(|a, b| a.name.cmp(&b.name));
+
+    vulnerabilities
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system::cpu::VulnerabilityStatus;
+    use crate::system_info::cpuinfo::{identify, parse_cpuinfo, read_caches, read_vulnerabilities};
+    use crate::utils::sysfs::fake_tree;
+
+    #[test]
+    fn reads_vulnerabilities() {
+        let root = fake_tree("cpuinfo-vulnerabilities", &[
+            ("spectre_v2", "Mitigation: Retpolines; IBPB: conditional; STIBP: disabled; RSB filling\n"),
+            ("meltdown", "Not affected\n"),
+            ("mds", "Vulnerable: Clear CPU buffers attempted, no microcode; SMT vulnerable\n"),
+        ]);
+
+        let vulnerabilities = read_vulnerabilities(&root);
+        assert_eq!(vulnerabilities.iter().map(|vulnerability| vulnerability.name.as_str()).collect::<Vec<_>>(), vec!["mds", "meltdown", "spectre_v2"]);
+        assert_eq!(vulnerabilities[0].status, VulnerabilityStatus::Vulnerable);
+        assert_eq!(vulnerabilities[2].detail, "Mitigation: Retpolines; IBPB: conditional; STIBP: disabled; RSB filling");
+
+        assert!(read_vulnerabilities(&root.join("missing")).is_empty());
+    }
+
+    #[test]
+    fn parses_x86_cpuinfo() {
+        let identification = parse_cpuinfo("processor\t: 0
+vendor_id\t: GenuineIntel
+cpu family\t: 6
+model\t\t: 143
+model name\t: Intel(R) Xeon(R) Platinum 8480+
+stepping\t: 8
+microcode\t: 0x2b000590
+flags\t\t: fpu cx8 cmov mmx fxsr sse sse2 syscall lm pni ssse3 cx16 sse4_1 sse4_2 popcnt lahf_lm avx avx2 bmi1 bmi2 f16c fma abm movbe xsave aes sha_ni avx512f avx512cd avx512bw avx512dq avx512vl
+
+processor\t: 1
+vendor_id\t: AuthenticAMD
+");
+
+        assert_eq!(identification.vendor.as_deref(), Some("GenuineIntel"));
+        assert_eq!((identification.family, identification.model, identification.stepping), (Some(6), Some(143), Some(8)));
+        assert_eq!(identification.microcode.as_deref(), Some("0x2b000590"));
+        assert_eq!(identification.x86_64_level(), Some(4));
+        assert_eq!(
+            identification.extensions(),
+            vec!["SSE4.2", "AVX", "AVX2", "FMA", "F16C", "BMI1", "BMI2", "AES-NI", "SHA", "AVX-512F", "AVX-512CD", "AVX-512BW", "AVX-512DQ", "AVX-512VL"],
+        );
+
+        // Without AVX-512 and MOVBE the level stops at v2.
+        let identification = parse_cpuinfo("flags : fpu cx8 cmov mmx fxsr sse sse2 syscall lm pni ssse3 cx16 sse4_1 sse4_2 popcnt lahf_lm avx avx2");
+        assert_eq!(identification.x86_64_level(), Some(2));
+        assert_eq!(parse_cpuinfo("flags : fpu sse sse2").x86_64_level(), None);
+    }
+
+    #[test]
+    fn parses_arm_cpuinfo() {
+        let identification = parse_cpuinfo("processor\t: 0
+BogoMIPS\t: 50.00
+Features\t: fp asimd evtstrm aes pmull sha1 sha2 crc32 atomics fphp asimdhp cpuid asimdrdm lrcpc dcpop asimddp
+CPU implementer\t: 0x41
+CPU architecture: 8
+CPU variant\t: 0x3
+CPU part\t: 0xd0c
+CPU revision\t: 1
+");
+
+        assert_eq!(identification.vendor.as_deref(), Some("ARM"));
+        assert_eq!((identification.family, identification.model, identification.stepping), (Some(8), Some(0xd0c), Some(1)));
+        assert_eq!(identification.extensions(), vec!["NEON", "DotProd", "AES", "SHA2", "LSE", "CRC32"]);
+        assert_eq!(identification.x86_64_level(), None);
+    }
+
+    #[test]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn identifies_with_cpuid() {
+        let identification = identify("microcode\t: 0x1\n");
+
+        assert!(identification.vendor.is_some());
+        assert!(identification.x86_64_level() >= Some(1));
+        assert_eq!(identification.microcode.as_deref(), Some("0x1"));
+    }
+
+    #[test]
+    fn reads_caches() {
+        let root = fake_tree("cpuinfo-caches", &[
+            ("index0/level", "1\n"), ("index0/type", "Data\n"), ("index0/size", "48K\n"),
+            ("index1/level", "1\n"), ("index1/type", "Instruction\n"), ("index1/size", "32K\n"),
+            ("index3/level", "3\n"), ("index3/type", "Unified\n"), ("index3/size", "30720K\n"),
+            ("index2/level", "2\n"), ("index2/type", "Unified\n"), ("index2/size", "2048K\n"),
+            ("uevent", ""),
+        ]);
+
+        let caches = read_caches(&root);
+        assert_eq!(caches.iter().map(|cache| cache.name()).collect::<Vec<_>>(), vec!["L1d", "L1i", "L2", "L3"]);
+        assert_eq!(caches[3].size, 30 * 1024 * 1024);
+    }
+}
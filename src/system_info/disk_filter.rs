@@ -0,0 +1,129 @@
+use crate::cli::args::Cli;
+use crate::utils::glob::glob_match;
+
+// Kernel and virtual filesystems, and read-only images such as snaps, which hold no user data.
+const HIDDEN_FILE_SYSTEMS: [&str; 30] = [
+    "autofs", "binfmt_misc", "bpf", "cgroup", "cgroup2", "configfs", "debugfs", "devpts", "devtmpfs", "efivarfs",
+    "fusectl", "fuse.gvfsd-fuse", "fuse.portal", "fuse.snapfuse", "hugetlbfs", "mqueue", "nsfs", "overlay", "proc",
+    "pstore", "ramfs", "rpc_pipefs", "securityfs", "selinuxfs", "sysfs", "tmpfs", "tracefs", "squashfs", "erofs", "cramfs",
+];
+
+/// A mounted filesystem as listed by sysinfo.
+#[derive(Debug)]
+pub struct Mount<'a> {
+    pub device: &'a str,            // e.g. /dev/nvme0n1p2, tmpfs
+    pub mount_point: &'a str,
+    pub file_system: &'a str,
+    pub total_space: u64,           // Byte
+}
+
+/// Which mounts the disk section shows, from the command line.
+#[derive(Debug, Default)]
+pub struct DiskFilter {
+    pub all: bool,
+    pub include: Vec<String>,       // Mount point globs
+    pub exclude: Vec<String>,
+    pub types: Vec<String>,
+    pub hide_types: Vec<String>,
+    pub min_size: Option<u64>,      // Byte
+}
+
+impl DiskFilter {
+    pub fn from_cli(cli: &Cli) -> DiskFilter {
+        DiskFilter {
+            all: cli.all_disks,
+            include: cli.disk_include.clone(),
+            exclude: cli.disk_exclude.clone(),
+            types: cli.disk_types.clone(),
+            hide_types: cli.hide_disk_types.clone(),
+            min_size: cli.min_disk_size.map(|size| (size * 1024f64.powi(3)) as u64),
+        }
+    }
+
+    fn allows(&self, mount: &Mount) -> bool {
+        let included = !self.include.is_empty() && self.include.iter().any(|pattern| glob_match(pattern, mount.mount_point));
+        let typed = self.types.iter().any(|file_system| file_system == mount.file_system);
+
+        // Pseudo filesystems stay hidden unless asked for by name or mount point.
+        if !self.all && !included && !typed && (HIDDEN_FILE_SYSTEMS.contains(&mount.file_system) || mount.total_space == 0) {
+            return false;
+        }
+
+        (self.include.is_empty() || included)
+            && !self.exclude.iter().any(|pattern| glob_match(pattern, mount.mount_point))
+            && (self.types.is_empty() || typed)
+            && !self.hide_types.iter().any(|file_system| file_system == mount.file_system)
+            && self.min_size.is_none_or(|min_size| mount.total_space >= min_size)
+    }
+
+    /// Indices of the mounts to show, grouped by block device. The first of each group is the mount
+    /// closest to the root, the others are bind mounts or subvolumes of the same filesystem. Other
+    /// sources such as "tmpfs" or NFS exports don't identify a filesystem and are never grouped.
+    pub fn apply(&self, mounts: &[Mount]) -> Vec<Vec<usize>> {
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+
+        for (index, mount) in mounts.iter().enumerate().filter(|(_, mount)| self.allows(mount)) {
+            let group = groups.iter_mut()
+                .find(|group| !self.all && mount.device.starts_with("/dev/") && mounts[group[0]].device == mount.device);
+            match group {
+                Some(group) => group.push(index),
+                None => groups.push(vec![index]),
+            }
+        }
+
+        for group in &mut groups {
+            group.sort_by_key(|index| (mounts[*index].mount_point.len(), *index));
+        }
+
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::disk_filter::{DiskFilter, Mount};
+
+    const GB: u64 = 1024 * 1024 * 1024;
+
+    fn mounts() -> Vec<Mount<'static>> {
+        vec![
+            Mount { device: "/dev/nvme0n1p2", mount_point: "/", file_system: "btrfs", total_space: 900 * GB },
+            Mount { device: "/dev/nvme0n1p1", mount_point: "/boot/efi", file_system: "vfat", total_space: GB / 2 },
+            Mount { device: "tmpfs", mount_point: "/dev/shm", file_system: "tmpfs", total_space: 16 * GB },
+            Mount { device: "/dev/loop3", mount_point: "/snap/core22/1380", file_system: "squashfs", total_space: GB / 10 },
+            Mount { device: "/dev/nvme0n1p2", mount_point: "/home", file_system: "btrfs", total_space: 900 * GB },
+            Mount { device: "/dev/nvme0n1p2", mount_point: "/run/flatpak/doc", file_system: "btrfs", total_space: 900 * GB },
+            Mount { device: "/dev/sda1", mount_point: "/mnt/backup", file_system: "ext4", total_space: 4000 * GB },
+        ]
+    }
+
+    #[test]
+    fn hides_pseudo_and_duplicates() {
+        let groups = DiskFilter::default().apply(&mounts());
+        assert_eq!(groups, vec![vec![0, 4, 5], vec![1], vec![6]]);
+
+        let groups = DiskFilter { all: true, ..Default::default() }.apply(&mounts());
+        assert_eq!(groups.len(), 7);
+    }
+
+    #[test]
+    fn applies_rules() {
+        let filter = DiskFilter { exclude: vec!["/boot*".into()], min_size: Some(1000 * GB), ..Default::default() };
+        assert_eq!(filter.apply(&mounts()), vec![vec![6]]);
+
+        let filter = DiskFilter { include: vec!["/mnt/*".into(), "/dev/shm".into()], ..Default::default() };
+        assert_eq!(filter.apply(&mounts()), vec![vec![2], vec![6]]);
+
+        let filter = DiskFilter { types: vec!["squashfs".into()], ..Default::default() };
+        assert_eq!(filter.apply(&mounts()), vec![vec![3]]);
+
+        let filter = DiskFilter { hide_types: vec!["vfat".into(), "ext4".into()], ..Default::default() };
+        assert_eq!(filter.apply(&mounts()), vec![vec![0, 4, 5]]);
+
+        // Every tmpfs is its own filesystem, even though they share the "tmpfs" source.
+        let mut mounts = mounts();
+        mounts.push(Mount { device: "tmpfs", mount_point: "/tmp", file_system: "tmpfs", total_space: 8 * GB });
+        let filter = DiskFilter { types: vec!["tmpfs".into()], ..Default::default() };
+        assert_eq!(filter.apply(&mounts), vec![vec![2], vec![7]]);
+    }
+}
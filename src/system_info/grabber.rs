@@ -1,7 +1,20 @@
 use sysinfo::System;
-use wmi::WMIError;
+use crate::cli::args::Cli;
+use crate::utils::error::WMIError;
 use crate::system_info::grabber::info_grab::Grabber;
 use crate::system::component::Component;
+use crate::system_info::battery_grabber::BatteryGrabber;
+use crate::system_info::network_grabber::NetworkGrabber;
+use crate::system_info::uptime_grabber::UptimeGrabber;
+use crate::system_info::session_grabber::SessionGrabber;
+use crate::system_info::terminal_grabber::TerminalGrabber;
+use crate::system_info::packages_grabber::PackagesGrabber;
+use crate::system_info::sensors_grabber::SensorsGrabber;
+use crate::system_info::memory_modules_grabber::MemoryModulesGrabber;
+use crate::system_info::board_grabber::BoardGrabber;
+use crate::system_info::security_grabber::SecurityGrabber;
+use crate::system_info::numa_grabber::NumaGrabber;
+use crate::system_info::storage_grabber::StorageGrabber;
 
 pub enum ComponentType {
     Cpu,
@@ -9,50 +22,106 @@ pub enum ComponentType {
     Disk,
     Memory,
     OS,
+    Battery,
+    Network,
+    Uptime,
+    Session,
+    Terminal,
+    Packages,
+    Sensors,
+    MemoryModules,
+    Board,
+    Security,
+    Numa,
+    Storage,
 }
 
-pub fn grab(sys: &mut System, component_type: &ComponentType) -> Result<Vec<Box<dyn Component>>, WMIError> {
+pub fn grab(sys: &mut System, cli: &Cli, component_type: &ComponentType) -> Result<Vec<Box<dyn Component>>, WMIError> {
     sys.refresh_all();
 
     match component_type {
-        ComponentType::Cpu => info_grab::CpuGrabber::grab(&sys),
-        ComponentType::Gpu => info_grab::GpuGrabber::grab(&sys),
-        ComponentType::Disk => info_grab::DiskGrabber::grab(&sys),
-        ComponentType::Memory => info_grab::MemoryGrabber::grab(&sys),
-        ComponentType::OS => info_grab::OSGrabber::grab(&sys),
+        ComponentType::Cpu => info_grab::CpuGrabber::grab(sys, cli),
+        ComponentType::Gpu => info_grab::GpuGrabber::grab(sys, cli),
+        ComponentType::Disk => info_grab::DiskGrabber::grab(sys, cli),
+        ComponentType::Memory => info_grab::MemoryGrabber::grab(sys, cli),
+        ComponentType::OS => info_grab::OSGrabber::grab(sys, cli),
+        ComponentType::Battery => BatteryGrabber::grab(sys, cli),
+        ComponentType::Network => NetworkGrabber::grab(sys, cli),
+        ComponentType::Uptime => UptimeGrabber::grab(sys, cli),
+        ComponentType::Session => SessionGrabber::grab(sys, cli),
+        ComponentType::Terminal => TerminalGrabber::grab(sys, cli),
+        ComponentType::Packages => PackagesGrabber::grab(sys, cli),
+        ComponentType::Sensors => SensorsGrabber::grab(sys, cli),
+        ComponentType::MemoryModules => MemoryModulesGrabber::grab(sys, cli),
+        ComponentType::Board => BoardGrabber::grab(sys, cli),
+        ComponentType::Security => SecurityGrabber::grab(sys, cli),
+        ComponentType::Numa => NumaGrabber::grab(sys, cli),
+        ComponentType::Storage => StorageGrabber::grab(sys, cli),
     }
 }
 
 pub mod info_grab {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    #[cfg(target_os = "windows")]
     use std::process::{Command};
     use sysinfo::{System, Disks};
+    use crate::cli::args::{Cli, DisplayStyle, OutputFormat};
     use crate::system::component::Component;
     use crate::system::cpu::CpuInfo;
+    #[cfg(target_os = "windows")]
     use crate::system::gpu::GpuInfo;
     use crate::system::disk::DiskInfo;
 
-    #[cfg(target_os = "windows")]
-    use wmi::{WMIError};
+    use crate::utils::error::WMIError;
     use crate::system::memory::MemoryInfo;
     use crate::system::os::OSInfo;
+    use crate::system_info::{cgroup, cpufreq, cpuinfo, disk_filter, hybrid, kernel, meminfo, mountinfo, throughput, virtualization};
+    use crate::utils::sysfs::{read_number, read_string, selected};
+    #[cfg(target_os = "windows")]
     use crate::system_info::nvidia_grabber::NvidiaGrabber;
 
     pub trait Grabber {
-        fn grab(sys: &System) -> Result<Vec<Box<dyn Component>>, WMIError>;
+        fn grab(sys: &System, cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError>;
     }
 
     pub struct CpuGrabber;
     impl Grabber for CpuGrabber {
-        fn grab(sys: &System) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        fn grab(sys: &System, _cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
             let mut cpu_list = Vec::new();
             let cpu = sys.cpus().first().unwrap();
 
-            cpu_list.push(Box::new(CpuInfo::new(
+            let mut cpu_info = CpuInfo::new(
                 cpu.brand().to_string(),
                 sys.cpus().len(),
                 cpu.cpu_usage(),
                 sys.cpus().iter().map(|c| c.frequency()).sum::<u64>(),
-            )) as Box<dyn Component>);
+            );
+            let proc_cpuinfo = fs::read_to_string(cpuinfo::CPUINFO_PATH).unwrap_or_default();
+            let identification = cpuinfo::identify(&proc_cpuinfo);
+            cpu_info.extensions = identification.extensions();
+            cpu_info.x86_64_level = identification.x86_64_level();
+            cpu_info.vendor = identification.vendor;
+            cpu_info.family = identification.family;
+            cpu_info.model = identification.model;
+            cpu_info.stepping = identification.stepping;
+            cpu_info.microcode = identification.microcode;
+            cpu_info.core_clusters = hybrid::read_core_clusters(Path::new(hybrid::DEVICES_PATH), &proc_cpuinfo);
+            cpu_info.caches = cpuinfo::read_caches(Path::new(cpuinfo::CACHE_PATH));
+            cpu_info.frequency_policies = cpufreq::read_policies(Path::new(cpufreq::CPUFREQ_PATH), Path::new(cpufreq::INTEL_PSTATE_PATH));
+            cpu_info.platform_profile = read_string(Path::new(cpufreq::PLATFORM_PROFILE_PATH));
+            cpu_info.vulnerabilities = cpuinfo::read_vulnerabilities(Path::new(cpuinfo::VULNERABILITIES_PATH));
+
+            // Containers and systemd slices may only get part of the host CPUs.
+            let limits = cgroup::current();
+            let host_cpus = sys.cpus().len();
+            cpu_info.cpuset = limits.cpuset.filter(|cpus| !cpus.is_empty() && cpus.len() < host_cpus);
+            cpu_info.cpu_limit = [limits.cpu_quota, cpu_info.cpuset.as_ref().map(|cpus| cpus.len() as f64)].into_iter()
+                .flatten()
+                .reduce(f64::min)
+                .filter(|limit| *limit < host_cpus as f64);
+
+            cpu_list.push(Box::new(cpu_info) as Box<dyn Component>);
 
             Ok(cpu_list)
         }
@@ -60,7 +129,8 @@ pub mod info_grab {
 
     pub struct GpuGrabber;
     impl Grabber for GpuGrabber {
-        fn grab(_sys: &System) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        fn grab(_sys: &System, _cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
+            #[cfg_attr(not(target_os = "windows"), allow(unused_mut))]
             let mut gpu_list = Vec::new();
 
             #[cfg(target_os = "windows")] {
@@ -110,7 +180,7 @@ pub mod info_grab {
                                 name = String::from(&data_table[index]);
 
                                 if name.contains("NVIDIA") {
-                                    let n_gpu_info = NvidiaGrabber::grab(_sys).expect("failed to analysis NVIDIA GPU");
+                                    let n_gpu_info = NvidiaGrabber::grab(_sys, _cli).expect("failed to analysis NVIDIA GPU");
                                     is_nvidia_gpu = true;
                                     gpu_list.extend(n_gpu_info);
                                     break;
@@ -143,19 +213,66 @@ pub mod info_grab {
 
     pub struct DiskGrabber;
     impl Grabber for DiskGrabber {
-        fn grab(_sys: &System) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        fn grab(_sys: &System, cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
             let mut disk_info = Vec::new();
 
             let disks = Disks::new_with_refreshed_list();
-
-            for disk in disks.list() {
-                let info = DiskInfo::new(
-                    disk.name().to_str().unwrap().to_string(),
+            let throughput = throughput::sample(cli);
+
+            // Hide pseudo filesystems and collapse mounts of the same device.
+            let fields = disks.list().iter()
+                .map(|disk| [disk.name(), disk.mount_point().as_os_str(), disk.file_system()].map(|field| field.to_string_lossy().to_string()))
+                .collect::<Vec<_>>();
+            let mounts = disks.list().iter().zip(&fields)
+                .map(|(disk, [device, mount_point, file_system])| disk_filter::Mount {
+                    device,
+                    mount_point,
+                    file_system,
+                    total_space: disk.total_space(),
+                })
+                .collect::<Vec<_>>();
+
+            // Mount details sysinfo leaves out, and filesystem UUIDs and labels from udev links.
+            let mountinfo = mountinfo::parse_mountinfo(&fs::read_to_string(mountinfo::MOUNTINFO_PATH).unwrap_or_default());
+            let uuids = mountinfo::read_disk_links(Path::new(mountinfo::DISK_BY_UUID_PATH));
+            let labels = mountinfo::read_disk_links(Path::new(mountinfo::DISK_BY_LABEL_PATH));
+            // Running zfs is only worth it where mount options are shown.
+            let detailed = cli.style == DisplayStyle::Detailed || cli.format == OutputFormat::Json;
+
+            for group in disk_filter::DiskFilter::from_cli(cli).apply(&mounts) {
+                let disk = &disks.list()[group[0]];
+                let name = disk.name().to_str().unwrap().to_string();
+                let disk_throughput = throughput.and_then(|throughput| throughput.disks.get(&block_device_name(&name)).cloned());
+
+                let mut info = DiskInfo::new(
+                    name,
                     disk.file_system().to_str().unwrap().to_string(),
                     disk.total_space(),
                     disk.available_space(),
                     disk.kind().to_string(),
+                    disk_throughput,
                 );
+                info.mount_points = group.iter().map(|index| mounts[*index].mount_point.to_string()).collect();
+
+                // The last mount on a mount point hides the earlier ones.
+                let mount_point = mounts[group[0]].mount_point;
+                if let Some(mount) = mountinfo.iter().rev().find(|mount| mount.mount_point == mount_point) {
+                    // Sources such as "tmpfs" or a ZFS dataset would resolve against the working directory.
+                    let device = Some(&mount.source).filter(|source| source.starts_with('/')).and_then(|source| fs::canonicalize(source).ok());
+                    info.uuid = device.as_ref().and_then(|device| uuids.get(device).cloned());
+                    info.label = device.as_ref().and_then(|device| labels.get(device).cloned());
+                    info.mount_options = mount.options.clone();
+                    // btrfs keeps compression among the filesystem options, ZFS as a dataset property.
+                    info.mount_options.extend(mount.super_options.iter().filter(|option| option.starts_with("compress")).cloned());
+                    if mount.fs_type == "zfs" && detailed {
+                        info.mount_options.extend(mountinfo::zfs_compression(&mount.source));
+                    }
+                    info.source = Some(mount.source.clone());
+                }
+                (info.inodes_total, info.inodes_free) = match mountinfo::inodes(mount_point) {
+                    Some((total, free)) => (Some(total), Some(free)),
+                    None => (None, None),
+                };
 
                 disk_info.push(Box::new(info) as Box<dyn Component>);
             }
@@ -164,17 +281,45 @@ pub mod info_grab {
         }
     }
 
+    /// Kernel name of a device node, e.g. `/dev/mapper/root` -> `dm-0`, as used by /proc/diskstats.
+    fn block_device_name(device: &str) -> String {
+        let device = fs::canonicalize(device).unwrap_or_else(|_| PathBuf::from(device));
+
+        device.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default()
+    }
+
     pub struct MemoryGrabber;
     impl Grabber for MemoryGrabber {
-        fn grab(sys: &System) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        fn grab(sys: &System, _cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
             let (used, total) = (sys.used_memory(), sys.total_memory());
 
-            let memory_info = vec![Box::new(
-                MemoryInfo::new(
-                    total,
-                    total - used,
-                )
-            ) as Box<dyn Component>];
+            let mut memory = MemoryInfo::new(
+                total,
+                total - used,
+            );
+            memory.swap_total = sys.total_swap();
+            memory.swap_used = sys.used_swap();
+
+            // Break down memory, swap, zram and huge pages where procfs exposes them.
+            let meminfo = fs::read_to_string(meminfo::MEMINFO_PATH).ok()
+                .map(|content| meminfo::parse_meminfo(&content))
+                .unwrap_or_default();
+            memory.breakdown = meminfo::breakdown(&meminfo);
+            memory.huge_pages = meminfo::huge_pages(
+                &meminfo,
+                read_string(Path::new(meminfo::TRANSPARENT_HUGEPAGE_PATH)).and_then(|choices| selected(&choices)),
+            );
+            memory.swaps = fs::read_to_string(meminfo::SWAPS_PATH).ok()
+                .map(|content| meminfo::parse_swaps(&content))
+                .unwrap_or_default();
+            memory.zram = meminfo::read_zram(Path::new(meminfo::BLOCK_PATH));
+
+            // Report against the cgroup limit when it is tighter than the host memory.
+            let limits = cgroup::current();
+            memory.limit = limits.memory_limit.filter(|limit| *limit < total);
+            memory.limit_used = memory.limit.and(limits.memory_usage);
+
+            let memory_info = vec![Box::new(memory) as Box<dyn Component>];
 
             Ok(memory_info)
         }
@@ -182,7 +327,7 @@ pub mod info_grab {
 
     pub struct OSGrabber;
     impl Grabber for OSGrabber {
-        fn grab(_sys: &System) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        fn grab(_sys: &System, _cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
             let mut os_info = Vec::new();
 
             let name = match System::long_os_version() {
@@ -195,9 +340,21 @@ pub mod info_grab {
                 None => { return Err(WMIError::SerdeError("failed to detect Host name.".into())) }
             };
 
-            os_info.push(Box::new(OSInfo::new(
-                name, host_name
-            )) as Box<dyn Component>);
+            let mut info = OSInfo::new(name, host_name);
+            info.kernel_name = read_string(Path::new(kernel::KERNEL_TYPE_PATH));
+            info.kernel_release = System::kernel_version();
+            info.architecture = Some(System::cpu_arch()).filter(|architecture| !architecture.is_empty());
+            info.os_release = kernel::OS_RELEASE_PATHS.iter()
+                .find_map(|path| fs::read_to_string(path).ok())
+                .map(|content| kernel::parse_os_release(&content))
+                .unwrap_or_default();
+            info.init_system = kernel::detect_init_system(Path::new("/proc"), Path::new("/run"));
+            info.taint = read_number(Path::new(kernel::TAINTED_PATH));
+            info.taint_flags = info.taint.map(kernel::decode_taint).unwrap_or_default();
+            info.command_line = read_string(Path::new(kernel::CMDLINE_PATH));
+            info.virtualization = virtualization::current();
+
+            os_info.push(Box::new(info) as Box<dyn Component>);
 
             Ok(os_info)
         }
@@ -0,0 +1,207 @@
+use std::cmp::Reverse;
+use std::collections::BTreeSet;
+use std::path::Path;
+use crate::system::cpu::CoreCluster;
+use crate::utils::sysfs::{parse_cpu_list, read_number, read_string};
+
+pub const DEVICES_PATH: &str = "/sys/devices";
+
+// Intel hybrid CPUs register a PMU per core type. Lunar Lake and Arrow Lake-H have a separate one
+// for their low power E-cores, Meteor Lake lists those under cpu_atom.
+const INTEL_CORE_TYPES: [(&str, &str); 3] = [("cpu_core", "P"), ("cpu_atom", "E"), ("cpu_lowpower", "LP-E")];
+
+// ARM core names by MIDR part number, for implementer ARM (0x41).
+const ARM_PARTS: [(u32, &str); 20] = [
+    (0xd03, "Cortex-A53"),
+    (0xd04, "Cortex-A35"),
+    (0xd05, "Cortex-A55"),
+    (0xd07, "Cortex-A57"),
+    (0xd08, "Cortex-A72"),
+    (0xd09, "Cortex-A73"),
+    (0xd0a, "Cortex-A75"),
+    (0xd0b, "Cortex-A76"),
+    (0xd0c, "Neoverse-N1"),
+    (0xd0d, "Cortex-A77"),
+    (0xd41, "Cortex-A78"),
+    (0xd44, "Cortex-X1"),
+    (0xd46, "Cortex-A510"),
+    (0xd47, "Cortex-A710"),
+    (0xd48, "Cortex-X2"),
+    (0xd4d, "Cortex-A715"),
+    (0xd4e, "Cortex-X3"),
+    (0xd80, "Cortex-A520"),
+    (0xd81, "Cortex-A720"),
+    (0xd82, "Cortex-X4"),
+];
+
+/// Group CPUs by core type on hybrid machines, fastest first. Machines with a single core type
+/// yield an empty list.
+pub fn read_core_clusters(devices: &Path, cpuinfo: &str) -> Vec<CoreCluster> {
+    let cpu_root = devices.join("system/cpu");
+    let online = parse_cpu_list(&read_string(&cpu_root.join("online")).unwrap_or_default());
+
+    let mut clusters = read_intel_clusters(devices);
+    if clusters.is_empty() {
+        clusters = read_arm_clusters(&cpu_root, &online, cpuinfo);
+    }
+    if clusters.len() < 2 {
+        return Vec::new();
+    }
+
+    for cluster in &mut clusters {
+        // SMT siblings share a core, count distinct sibling lists.
+        cluster.cores = cluster.cpus.iter()
+            .map(|cpu| read_string(&cpu_root.join(format!("cpu{}/topology/thread_siblings_list", cpu))).unwrap_or_else(|| cpu.to_string()))
+            .collect::<BTreeSet<_>>()
+            .len();
+        cluster.max_frequency = cluster.cpus.iter()
+            .filter_map(|cpu| read_number::<u64>(&cpu_root.join(format!("cpu{}/cpufreq/cpuinfo_max_freq", cpu))))
+            .max()
+            .map(|frequency| frequency / 1000);
+    }
+
+    clusters
+}
+
+fn read_intel_clusters(devices: &Path) -> Vec<CoreCluster> {
+    INTEL_CORE_TYPES.iter()
+        .filter_map(|(pmu, kind)| {
+            let cpus = parse_cpu_list(&read_string(&devices.join(pmu).join("cpus"))?);
+
+            Some(CoreCluster { kind: kind.to_string(), cpus, ..Default::default() })
+        })
+        .collect()
+}
+
+/// Group ARM cores by capacity, highest first, named after their MIDR part when known.
+fn read_arm_clusters(cpu_root: &Path, online: &[usize], cpuinfo: &str) -> Vec<CoreCluster> {
+    let parts = parse_parts(cpuinfo);
+
+    let mut groups: Vec<(u64, Option<u32>, Vec<usize>)> = Vec::new();
+    for cpu in online {
+        let capacity = read_number::<u64>(&cpu_root.join(format!("cpu{}/cpu_capacity", cpu))).unwrap_or(0);
+        let part = parts.iter().find(|(processor, _)| processor == cpu).map(|(_, part)| *part);
+
+        match groups.iter_mut().find(|(group_capacity, group_part, _)| *group_capacity == capacity && *group_part == part) {
+            Some((_, _, cpus)) => cpus.push(*cpu),
+            None => groups.push((capacity, part, vec![*cpu])),
+        }
+    }
+    groups.sort_by_key(|(capacity, _, _)| Reverse(*capacity));
+
+    let count = groups.len();
+    groups.into_iter()
+        .enumerate()
+        .map(|(index, (_, part, cpus))| {
+            let name = part.and_then(|part| ARM_PARTS.iter().find(|(number, _)| *number == part)).map(|(_, name)| name.to_string());
+            let kind = match index + 1 == count {
+                true => "E",
+                false => "P",
+            };
+
+            CoreCluster { kind: kind.to_string(), name, cpus, ..Default::default() }
+        })
+        .collect()
+}
+
+/// MIDR part number of each processor of /proc/cpuinfo, for the ARM implementer only.
+fn parse_parts(cpuinfo: &str) -> Vec<(usize, u32)> {
+    cpuinfo.split("\n\n")
+        .filter_map(|block| {
+            let field = |name: &str| block.lines()
+                .filter_map(|line| line.split_once(':'))
+                .find(|(key, _)| key.trim() == name)
+                .map(|(_, value)| value.trim());
+
+            if field("CPU implementer")? != "0x41" {
+                return None;
+            }
+            let processor = field("processor")?.parse().ok()?;
+            let part = u32::from_str_radix(field("CPU part")?.trim_start_matches("0x"), 16).ok()?;
+
+            Some((processor, part))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::hybrid::read_core_clusters;
+    use crate::utils::sysfs::fake_tree;
+
+    #[test]
+    fn reads_intel_hybrid() {
+        let mut files = vec![
+            ("cpu_core/cpus".to_string(), "0-11\n".to_string()),
+            ("cpu_atom/cpus".to_string(), "12-19\n".to_string()),
+            ("system/cpu/online".to_string(), "0-19\n".to_string()),
+        ];
+        for cpu in 0..20 {
+            // P-cores have two threads each.
+            let siblings = if cpu < 12 { format!("{}-{}", cpu / 2 * 2, cpu / 2 * 2 + 1) } else { cpu.to_string() };
+            let max = if cpu < 12 { "4700000" } else { "3500000" };
+            files.push((format!("system/cpu/cpu{}/topology/thread_siblings_list", cpu), siblings));
+            files.push((format!("system/cpu/cpu{}/cpufreq/cpuinfo_max_freq", cpu), max.to_string()));
+        }
+        let root = fake_tree("hybrid-intel", &files.iter().map(|(path, content)| (path.as_str(), content.as_str())).collect::<Vec<_>>());
+
+        let clusters = read_core_clusters(&root, "");
+        assert_eq!(clusters.len(), 2);
+        assert_eq!((clusters[0].kind.as_str(), clusters[0].cores, clusters[0].cpus.len()), ("P", 6, 12));
+        assert_eq!((clusters[1].kind.as_str(), clusters[1].cores, clusters[1].cpus.len()), ("E", 8, 8));
+        assert_eq!(clusters[0].max_frequency, Some(4700));
+        assert_eq!(clusters[1].max_frequency, Some(3500));
+    }
+
+    #[test]
+    fn reads_intel_low_power_cores() {
+        let root = fake_tree("hybrid-intel-lowpower", &[
+            ("cpu_core/cpus", "0-7\n"),
+            ("cpu_atom/cpus", "8-15\n"),
+            ("cpu_lowpower/cpus", "16-17\n"),
+            ("system/cpu/online", "0-17\n"),
+        ]);
+
+        let clusters = read_core_clusters(&root, "");
+        assert_eq!(
+            clusters.iter().map(|cluster| (cluster.kind.as_str(), cluster.cores)).collect::<Vec<_>>(),
+            vec![("P", 8), ("E", 8), ("LP-E", 2)],
+        );
+    }
+
+    #[test]
+    fn reads_arm_big_little() {
+        let root = fake_tree("hybrid-arm", &[
+            ("system/cpu/online", "0-7\n"),
+            ("system/cpu/cpu0/cpu_capacity", "325\n"),
+            ("system/cpu/cpu1/cpu_capacity", "325\n"),
+            ("system/cpu/cpu2/cpu_capacity", "325\n"),
+            ("system/cpu/cpu3/cpu_capacity", "325\n"),
+            ("system/cpu/cpu4/cpu_capacity", "825\n"),
+            ("system/cpu/cpu5/cpu_capacity", "825\n"),
+            ("system/cpu/cpu6/cpu_capacity", "825\n"),
+            ("system/cpu/cpu7/cpu_capacity", "1024\n"),
+        ]);
+        let cpuinfo = (0..8)
+            .map(|cpu| format!("processor\t: {}\nCPU implementer\t: 0x41\nCPU part\t: {}\n", cpu, match cpu { 0..=3 => "0xd46", 4..=6 => "0xd47", _ => "0xd48" }))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let clusters = read_core_clusters(&root, &cpuinfo);
+        assert_eq!(clusters.iter().map(|cluster| cluster.name.as_deref().unwrap()).collect::<Vec<_>>(), vec!["Cortex-X2", "Cortex-A710", "Cortex-A510"]);
+        assert_eq!(clusters.iter().map(|cluster| cluster.kind.as_str()).collect::<Vec<_>>(), vec!["P", "P", "E"]);
+        assert_eq!(clusters[2].cpus, vec![0, 1, 2, 3]);
+        assert_eq!(clusters[2].cores, 4);
+    }
+
+    #[test]
+    fn ignores_homogeneous_cpus() {
+        let root = fake_tree("hybrid-none", &[
+            ("system/cpu/online", "0-1\n"),
+            ("system/cpu/cpu0/cpu_capacity", "1024\n"),
+            ("system/cpu/cpu1/cpu_capacity", "1024\n"),
+        ]);
+
+        assert!(read_core_clusters(&root, "").is_empty());
+    }
+}
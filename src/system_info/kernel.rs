@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::path::Path;
+use crate::system::os::OsRelease;
+use crate::utils::sysfs::read_string;
+
+pub const OS_RELEASE_PATHS: [&str; 2] = ["/etc/os-release", "/usr/lib/os-release"];
+pub const KERNEL_TYPE_PATH: &str = "/proc/sys/kernel/ostype";
+pub const TAINTED_PATH: &str = "/proc/sys/kernel/tainted";
+pub const CMDLINE_PATH: &str = "/proc/cmdline";
+
+// Taint bits, see Documentation/admin-guide/tainted-kernels.rst
+const TAINT_FLAGS: [(char, &str); 20] = [
+    ('P', "proprietary module was loaded"),
+    ('F', "module was force loaded"),
+    ('S', "kernel running on an out of specification system"),
+    ('R', "module was force unloaded"),
+    ('M', "processor reported a machine check exception"),
+    ('B', "bad page referenced or unexpected page flags"),
+    ('U', "taint requested by userspace application"),
+    ('D', "kernel died recently, i.e. there was an OOPS or BUG"),
+    ('A', "ACPI table overridden by user"),
+    ('W', "kernel issued warning"),
+    ('C', "staging driver was loaded"),
+    ('I', "workaround for bug in platform firmware applied"),
+    ('O', "externally-built (out-of-tree) module was loaded"),
+    ('E', "unsigned module was loaded"),
+    ('L', "soft lockup occurred"),
+    ('K', "kernel has been live patched"),
+    ('X', "auxiliary taint, defined for and used by distros"),
+    ('T', "kernel was built with the struct randomization plugin"),
+    ('N', "an in-kernel test has been run"),
+    ('J', "userspace used a mutating debug operation in fwctl"),
+];
+
+/// Parse os-release(5) content. Values may be quoted with single or double quotes.
+pub fn parse_os_release(content: &str) -> OsRelease {
+    let fields = content.lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| {
+            let value = value.trim();
+            let value = value.strip_prefix('"').and_then(|value| value.strip_suffix('"'))
+                .or_else(|| value.strip_prefix('\'').and_then(|value| value.strip_suffix('\'')))
+                .unwrap_or(value);
+
+            (key.trim(), value.replace("\\\"", "\""))
+        })
+        .filter(|(_, value)| !value.is_empty())
+        .collect::<HashMap<_, _>>();
+
+    let field = |key: &str| fields.get(key).cloned();
+
+    OsRelease {
+        id: field("ID"),
+        version_id: field("VERSION_ID"),
+        version_codename: field("VERSION_CODENAME"),
+        build_id: field("BUILD_ID"),
+        variant: field("VARIANT").or_else(|| field("VARIANT_ID")),
+    }
+}
+
+/// Readable description of each bit set in /proc/sys/kernel/tainted, e.g. "W (kernel issued warning)".
+pub fn decode_taint(tainted: u64) -> Vec<String> {
+    (0..64)
+        .filter(|bit| tainted & (1u64 << bit) != 0)
+        .map(|bit| match TAINT_FLAGS.get(bit) {
+            Some((flag, description)) => format!("{} ({})", flag, description),
+            None => format!("bit {} (unknown)", bit),
+        })
+        .collect()
+}
+
+/// Name of the init system, from the command name of PID 1 and the runtime directories init systems create.
+pub fn detect_init_system(proc_root: &Path, run_root: &Path) -> Option<String> {
+    let command = read_string(&proc_root.join("1/comm"));
+
+    // Many init systems run as a binary called "init", tell them apart by their runtime state.
+    let by_runtime = [
+        ("systemd/system", "systemd"),
+        ("openrc", "OpenRC"),
+        ("runit", "runit"),
+        ("s6", "s6"),
+        ("dinit", "dinit"),
+    ];
+
+    match command.as_deref() {
+        Some("systemd") => Some("systemd".to_string()),
+        Some("init") | None => by_runtime.iter()
+            .find(|(path, _)| run_root.join(path).exists())
+            .map(|(_, name)| name.to_string())
+            .or(command),
+        Some(command) => Some(command.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::kernel::{decode_taint, detect_init_system, parse_os_release};
+    use crate::utils::sysfs::fake_tree;
+
+    #[test]
+    fn parses_os_release() {
+        let content = "\
+PRETTY_NAME=\"Fedora Linux 40 (Workstation Edition)\"
+ID=fedora
+VERSION_ID=40
+VERSION_CODENAME=\"\"
+# comment
+VARIANT='Workstation Edition'
+BUILD_ID=\"20240423.0\"
+";
+
+        let release = parse_os_release(content);
+        assert_eq!(release.id.as_deref(), Some("fedora"));
+        assert_eq!(release.version_id.as_deref(), Some("40"));
+        assert_eq!(release.version_codename, None);
+        assert_eq!(release.variant.as_deref(), Some("Workstation Edition"));
+        assert_eq!(release.build_id.as_deref(), Some("20240423.0"));
+    }
+
+    #[test]
+    fn decodes_taint() {
+        assert!(decode_taint(0).is_empty());
+
+        let flags = decode_taint((1 << 0) | (1 << 9) | (1 << 12));
+        assert_eq!(flags.len(), 3);
+        assert!(flags[0].starts_with("P ("));
+        assert!(flags[1].starts_with("W ("));
+        assert!(flags[2].starts_with("O ("));
+    }
+
+    #[test]
+    fn detects_init_system() {
+        let root = fake_tree("init-system", &[
+            ("proc-systemd/1/comm", "systemd\n"),
+            ("proc-init/1/comm", "init\n"),
+            ("run-openrc/openrc/softlevel", ""),
+            ("run-empty/.keep", ""),
+        ]);
+
+        assert_eq!(detect_init_system(&root.join("proc-systemd"), &root.join("run-empty")).as_deref(), Some("systemd"));
+        assert_eq!(detect_init_system(&root.join("proc-init"), &root.join("run-openrc")).as_deref(), Some("OpenRC"));
+        assert_eq!(detect_init_system(&root.join("proc-init"), &root.join("run-empty")).as_deref(), Some("init"));
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use crate::system::memory::{HugePages, MemoryBreakdown, SwapDevice, ZramDevice};
+use crate::utils::sysfs::{read_number, read_string, selected};
+
+pub const MEMINFO_PATH: &str = "/proc/meminfo";
+pub const SWAPS_PATH: &str = "/proc/swaps";
+pub const BLOCK_PATH: &str = "/sys/block";
+pub const TRANSPARENT_HUGEPAGE_PATH: &str = "/sys/kernel/mm/transparent_hugepage/enabled";
+
+/// Parse /proc/meminfo into bytes. Sizes are given in kB, page counts such as HugePages_Total have no unit.
+pub fn parse_meminfo(content: &str) -> HashMap<String, u64> {
+    content.lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            let mut value = value.split_whitespace();
+            let number = value.next()?.parse::<u64>().ok()?;
+
+            match value.next() {
+                Some("kB") => Some((key.to_string(), number * 1024)),
+                _ => Some((key.to_string(), number)),
+            }
+        })
+        .collect()
+}
+
+pub fn breakdown(meminfo: &HashMap<String, u64>) -> Option<MemoryBreakdown> {
+    let field = |key: &str| meminfo.get(key).copied().unwrap_or(0);
+
+    Some(MemoryBreakdown {
+        free: *meminfo.get("MemFree")?,
+        // Kernels older than 3.14 don't estimate available memory.
+        available: meminfo.get("MemAvailable").copied()
+            .unwrap_or(field("MemFree") + field("Buffers") + field("Cached")),
+        buffers: field("Buffers"),
+        // Same accounting as free(1): page cache plus reclaimable slab.
+        cached: field("Cached") + field("SReclaimable"),
+        shared: field("Shmem"),
+        slab: field("Slab"),
+    })
+}
+
+pub fn huge_pages(meminfo: &HashMap<String, u64>, transparent: Option<String>) -> Option<HugePages> {
+    Some(HugePages {
+        total: *meminfo.get("HugePages_Total")?,
+        free: meminfo.get("HugePages_Free").copied().unwrap_or(0),
+        page_size: meminfo.get("Hugepagesize").copied().unwrap_or(0),
+        transparent,
+    })
+}
+
+/// Parse /proc/swaps, whose sizes are in KiB, skipping the header line.
+pub fn parse_swaps(content: &str) -> Vec<SwapDevice> {
+    content.lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [name, kind, size, used, priority, ..] = fields.as_slice() else {
+                return None;
+            };
+
+            Some(SwapDevice {
+                // Paths with spaces are escaped as \040.
+                name: name.replace("\\040", " "),
+                kind: kind.to_string(),
+                size: size.parse::<u64>().ok()? * 1024,
+                used: used.parse::<u64>().ok()? * 1024,
+                priority: priority.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Read the initialized zram devices under a /sys/block directory.
+pub fn read_zram(block_root: &Path) -> Vec<ZramDevice> {
+    let Ok(entries) = fs::read_dir(block_root) else {
+        return Vec::new();
+    };
+
+    let mut devices = entries.flatten()
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| name.starts_with("zram"))
+        .filter_map(|name| {
+            let device = block_root.join(&name);
+            let disk_size = read_number::<u64>(&device.join("disksize")).filter(|size| *size > 0)?;
+
+            // mm_stat: orig_data_size compr_data_size mem_used_total ...
+            let mm_stat = read_string(&device.join("mm_stat")).unwrap_or_default()
+                .split_whitespace()
+                .filter_map(|field| field.parse::<u64>().ok())
+                .collect::<Vec<_>>();
+
+            Some(ZramDevice {
+                algorithm: read_string(&device.join("comp_algorithm")).and_then(|algorithms| selected(&algorithms)),
+                disk_size,
+                original_size: mm_stat.first().copied().unwrap_or(0),
+                compressed_size: mm_stat.get(1).copied().unwrap_or(0),
+                memory_used: mm_stat.get(2).copied().unwrap_or(0),
+                name,
+            })
+        })
+        .collect::<Vec<_>>();
+    devices.sort_by(|a, b| a.name.cmp(&b.name));
+
+    devices
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::meminfo::{breakdown, huge_pages, parse_meminfo, parse_swaps, read_zram};
+    use crate::utils::sysfs::fake_tree;
+
+    const MEMINFO: &str = "MemTotal:       16303428 kB
+MemFree:         4194304 kB
+MemAvailable:   10485760 kB
+Buffers:          524288 kB
+Cached:          5242880 kB
+Shmem:            262144 kB
+Slab:             786432 kB
+SReclaimable:     524288 kB
+HugePages_Total:       4
+HugePages_Free:        2
+Hugepagesize:       2048 kB
+";
+
+    #[test]
+    fn parses_meminfo() {
+        let meminfo = parse_meminfo(MEMINFO);
+        assert_eq!(meminfo["MemTotal"], 16303428 * 1024);
+        assert_eq!(meminfo["HugePages_Total"], 4);
+
+        let breakdown = breakdown(&meminfo).unwrap();
+        assert_eq!(breakdown.available, 10 * 1024u64.pow(3));
+        assert_eq!(breakdown.cached, (5242880 + 524288) * 1024);
+        assert_eq!(breakdown.shared, 256 * 1024u64.pow(2));
+
+        let huge_pages = huge_pages(&meminfo, Some("madvise".into())).unwrap();
+        assert_eq!((huge_pages.total, huge_pages.free, huge_pages.page_size), (4, 2, 2 * 1024u64.pow(2)));
+    }
+
+    #[test]
+    fn parses_swaps() {
+        let swaps = parse_swaps("Filename\t\t\t\tType\t\tSize\t\tUsed\t\tPriority
+/dev/zram0                              partition\t8388604\t\t1024\t\t100
+/swap\\040file                           file\t\t2097148\t\t0\t\t-2
+");
+
+        assert_eq!(swaps.len(), 2);
+        assert_eq!(swaps[0].name, "/dev/zram0");
+        assert_eq!(swaps[0].used, 1024 * 1024);
+        assert_eq!(swaps[0].priority, 100);
+        assert_eq!(swaps[1].name, "/swap file");
+        assert_eq!(swaps[1].kind, "file");
+        assert_eq!(swaps[1].priority, -2);
+    }
+
+    #[test]
+    fn reads_zram() {
+        let root = fake_tree("meminfo-zram", &[
+            ("zram0/disksize", "8589934592\n"),
+            ("zram0/comp_algorithm", "lzo lzo-rle lz4 [zstd]\n"),
+            ("zram0/mm_stat", "3221225472 1073741824 1140850688 0 1140850688 12 0 0 0\n"),
+            ("zram1/disksize", "0\n"),
+            ("sda/size", "1000\n"),
+        ]);
+
+        let zram = read_zram(&root);
+        assert_eq!(zram.len(), 1);
+        assert_eq!(zram[0].algorithm.as_deref(), Some("zstd"));
+        assert_eq!(zram[0].compression_ratio(), Some(3f64));
+        assert_eq!(zram[0].memory_used, 1140850688);
+    }
+}
@@ -0,0 +1,215 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use sysinfo::System;
+use crate::cli::args::Cli;
+use crate::system::component::Component;
+use crate::system::memory_modules::{MemoryArray, MemoryModule, MemoryModulesInfo};
+use crate::system_info::grabber::info_grab::Grabber;
+use crate::utils::error::WMIError;
+use crate::utils::smbios::{parse_table, Structure};
+
+const DMI_TABLE_PATH: &str = "/sys/firmware/dmi/tables/DMI";
+
+const PHYSICAL_MEMORY_ARRAY: u8 = 16;
+const MEMORY_DEVICE: u8 = 17;
+
+// Memory Error Correction Types of type 16, starting at 0x01.
+const ERROR_CORRECTION_TYPES: [&str; 7] = ["Other", "Unknown", "None", "Parity", "Single-bit ECC", "Multi-bit ECC", "CRC"];
+
+// Form factors of type 17, starting at 0x01.
+const FORM_FACTORS: [&str; 16] = [
+    "Other", "Unknown", "SIMM", "SIP", "Chip", "DIP", "ZIP", "Proprietary Card",
+    "DIMM", "TSOP", "Row of chips", "RIMM", "SODIMM", "SRIMM", "FB-DIMM", "Die",
+];
+
+// Memory types of type 17, starting at 0x01.
+const MEMORY_TYPES: [&str; 36] = [
+    "Other", "Unknown", "DRAM", "EDRAM", "VRAM", "SRAM", "RAM", "ROM", "Flash", "EEPROM", "FEPROM", "EPROM",
+    "CDRAM", "3DRAM", "SDRAM", "SGRAM", "RDRAM", "DDR", "DDR2", "DDR2 FB-DIMM", "Reserved", "Reserved", "Reserved",
+    "DDR3", "FBD2", "DDR4", "LPDDR", "LPDDR2", "LPDDR3", "LPDDR4", "Logical non-volatile device", "HBM", "HBM2",
+    "DDR5", "LPDDR5", "HBM3",
+];
+
+pub struct MemoryModulesGrabber;
+
+impl Grabber for MemoryModulesGrabber {
+    fn grab(_sys: &System, _cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        let memory_modules_info = read_memory_modules(Path::new(DMI_TABLE_PATH))
+            .map(|modules| Box::new(modules) as Box<dyn Component>)
+            .into_iter()
+            .collect();
+
+        Ok(memory_modules_info)
+    }
+}
+
+/// Read DIMM slots from the SMBIOS table. The table is only readable by root, which is reported
+/// rather than silently skipped; machines without SMBIOS get nothing.
+fn read_memory_modules(path: &Path) -> Option<MemoryModulesInfo> {
+    match fs::read(path) {
+        Ok(table) => parse_memory_modules(&table),
+        Err(error) if error.kind() == ErrorKind::PermissionDenied => Some(MemoryModulesInfo {
+            error: Some(format!("{} is only readable by root", path.display())),
+            ..Default::default()
+        }),
+        Err(_) => None,
+    }
+}
+
+fn parse_memory_modules(table: &[u8]) -> Option<MemoryModulesInfo> {
+    let structures = parse_table(table);
+
+    let arrays = structures.iter()
+        .filter(|structure| structure.kind == PHYSICAL_MEMORY_ARRAY)
+        // Skip arrays used as cache or video memory, 0x03 is system memory.
+        .filter(|structure| structure.byte(0x05) == Some(0x03))
+        .collect::<Vec<_>>();
+
+    let modules = structures.iter()
+        .filter(|structure| structure.kind == MEMORY_DEVICE)
+        .filter(|structure| arrays.is_empty() || arrays.iter().any(|array| structure.word(0x04) == Some(array.handle)))
+        .map(|structure| {
+            let array = arrays.iter().find(|array| structure.word(0x04) == Some(array.handle));
+            parse_memory_device(structure, array.is_some_and(|array| has_ecc(array)))
+        })
+        .collect::<Vec<_>>();
+
+    if modules.is_empty() {
+        return None;
+    }
+
+    Some(MemoryModulesInfo {
+        arrays: arrays.iter().map(|array| parse_memory_array(array)).collect(),
+        modules,
+        error: None,
+    })
+}
+
+fn lookup(table: &[&str], value: Option<u8>) -> Option<String> {
+    let value = table.get((value? as usize).checked_sub(1)?)?;
+
+    match *value {
+        "Other" | "Unknown" | "Reserved" => None,
+        value => Some(value.to_string()),
+    }
+}
+
+fn has_ecc(array: &Structure) -> bool {
+    matches!(array.byte(0x06), Some(0x05..=0x07))
+}
+
+fn parse_memory_array(array: &Structure) -> MemoryArray {
+    // Maximum capacity is in KiB, 0x80000000 defers to the extended capacity in bytes.
+    let maximum_capacity = match array.dword(0x07) {
+        Some(0x8000_0000) => array.qword(0x0F),
+        Some(capacity) => Some(capacity as u64 * 1024),
+        None => None,
+    };
+
+    MemoryArray {
+        maximum_capacity,
+        slots: array.word(0x0D).unwrap_or(0),
+        error_correction: lookup(&ERROR_CORRECTION_TYPES, array.byte(0x06)),
+    }
+}
+
+fn parse_memory_device(device: &Structure, array_ecc: bool) -> MemoryModule {
+    // Size is in MiB, or KiB with bit 15 set; 0 is an empty slot and 0x7FFF defers to the
+    // extended size in MiB.
+    let size = match device.word(0x0C) {
+        Some(0) | Some(0xFFFF) | None => None,
+        Some(0x7FFF) => device.dword(0x1C).map(|size| (size & 0x7FFF_FFFF) as u64 * 1024u64.pow(2)),
+        Some(size) if size & 0x8000 != 0 => Some((size & 0x7FFF) as u64 * 1024),
+        Some(size) => Some(size as u64 * 1024u64.pow(2)),
+    };
+
+    // Speeds of 0xFFFF defer to the extended speeds of SMBIOS 3.3, 0 is unknown.
+    let speed = |offset: usize, extended: usize| match device.word(offset) {
+        Some(0xFFFF) => device.dword(extended).map(|speed| speed & 0x7FFF_FFFF),
+        speed => speed.map(|speed| speed as u32),
+    }.filter(|speed| *speed > 0);
+
+    // ECC modules have a wider total width than data width, e.g. 72 and 64 bits.
+    let (total_width, data_width) = (device.word(0x08).unwrap_or(0xFFFF), device.word(0x0A).unwrap_or(0xFFFF));
+    let ecc = array_ecc && total_width != 0xFFFF && data_width != 0xFFFF && total_width > data_width;
+
+    MemoryModule {
+        locator: device.string(0x10).unwrap_or_else(|| format!("Handle 0x{:04X}", device.handle)),
+        bank: device.string(0x11),
+        memory_type: size.and(lookup(&MEMORY_TYPES, device.byte(0x12))),
+        form_factor: size.and(lookup(&FORM_FACTORS, device.byte(0x0E))),
+        speed: size.and(speed(0x15, 0x54)),
+        configured_speed: size.and(speed(0x20, 0x58)),
+        manufacturer: size.and(device.string(0x17)),
+        part_number: size.and(device.string(0x1A)),
+        rank: size.and(device.byte(0x1B)).map(|attributes| attributes & 0x0F).filter(|rank| *rank > 0),
+        ecc: size.is_some() && ecc,
+        size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use crate::system_info::memory_modules_grabber::{parse_memory_modules, read_memory_modules};
+
+    fn fixture(name: &str) -> Vec<u8> {
+        fs::read(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)).unwrap()
+    }
+
+    #[test]
+    fn parses_ddr5_desktop() {
+        let info = parse_memory_modules(&fixture("smbios-ddr5.bin")).unwrap();
+
+        assert_eq!(info.arrays.len(), 1);
+        assert_eq!(info.arrays[0].slots, 4);
+        assert_eq!(info.arrays[0].maximum_capacity, Some(192 * 1024u64.pow(3)));
+        assert_eq!(info.arrays[0].error_correction.as_deref(), Some("None"));
+
+        assert_eq!(info.modules.len(), 4);
+        let module = &info.modules[1];
+        assert_eq!(module.locator, "DIMM_A2");
+        assert_eq!(module.bank.as_deref(), Some("BANK 0"));
+        assert_eq!(module.size, Some(16 * 1024u64.pow(3)));
+        assert_eq!(module.memory_type.as_deref(), Some("DDR5"));
+        assert_eq!(module.form_factor.as_deref(), Some("DIMM"));
+        assert_eq!(module.speed, Some(5600));
+        assert_eq!(module.configured_speed, Some(4800));
+        assert_eq!(module.manufacturer.as_deref(), Some("Kingston"));
+        assert_eq!(module.part_number.as_deref(), Some("KF556C40-16"));
+        assert_eq!(module.rank, Some(1));
+        assert!(!module.ecc);
+
+        // Empty slots keep their label only.
+        assert_eq!(info.modules[0].locator, "DIMM_A1");
+        assert_eq!(info.modules[0].size, None);
+        assert_eq!(info.modules[0].manufacturer, None);
+    }
+
+    #[test]
+    fn parses_ddr4_ecc_server() {
+        let info = parse_memory_modules(&fixture("smbios-ddr4-ecc.bin")).unwrap();
+
+        assert_eq!(info.arrays[0].error_correction.as_deref(), Some("Multi-bit ECC"));
+        assert_eq!(info.modules.len(), 2);
+
+        let module = &info.modules[0];
+        assert_eq!(module.size, Some(32 * 1024u64.pow(3)));
+        assert_eq!(module.memory_type.as_deref(), Some("DDR4"));
+        assert_eq!(module.speed, Some(3200));
+        assert_eq!(module.rank, Some(2));
+        assert!(module.ecc);
+
+        // SMBIOS 2.8 has no extended speeds and no placeholder strings survive.
+        assert_eq!(info.modules[1].size, Some(32 * 1024u64.pow(3)));
+        assert_eq!(info.modules[1].part_number, None);
+    }
+
+    #[test]
+    fn degrades_without_table() {
+        assert!(read_memory_modules(Path::new("/nonexistent/DMI")).is_none());
+        assert!(parse_memory_modules(&[127, 4, 0xFF, 0xFF, 0, 0]).is_none());
+    }
+}
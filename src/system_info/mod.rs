@@ -1,2 +1,25 @@
 pub mod grabber;
+pub mod cgroup;
+pub mod cpufreq;
+pub mod cpuinfo;
+pub mod disk_filter;
+pub mod hybrid;
+pub mod kernel;
+pub mod meminfo;
+pub mod mountinfo;
+pub mod battery_grabber;
+pub mod network_grabber;
+pub mod throughput;
+pub mod virtualization;
+pub mod uptime_grabber;
+pub mod session_grabber;
+pub mod terminal_grabber;
+pub mod packages_grabber;
+pub mod sensors_grabber;
+pub mod memory_modules_grabber;
+pub mod board_grabber;
+pub mod security_grabber;
+pub mod numa_grabber;
+pub mod storage_grabber;
+#[cfg(target_os = "windows")]
 pub mod nvidia_grabber;
\ No newline at end of file
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+use crate::utils::command::output_with_timeout;
+
+pub const MOUNTINFO_PATH: &str = "/proc/self/mountinfo";
+pub const DISK_BY_UUID_PATH: &str = "/dev/disk/by-uuid";
+pub const DISK_BY_LABEL_PATH: &str = "/dev/disk/by-label";
+
+/// A line of /proc/<pid>/mountinfo.
+#[derive(Debug, Default)]
+pub struct Mount {
+    pub root: String,               // Directory of the filesystem mounted, "/" unless a bind mount or subvolume
+    pub mount_point: String,
+    pub options: Vec<String>,       // Per mount options, e.g. rw, noatime
+    pub fs_type: String,
+    pub source: String,             // e.g. /dev/nvme0n1p2, tmpfs
+    pub super_options: Vec<String>, // Filesystem options, e.g. compress=zstd:3
+}
+
+pub fn parse_mountinfo(mountinfo: &str) -> Vec<Mount> {
+    mountinfo.lines()
+        .filter_map(|line| {
+            // Optional fields end with a lone "-", followed by the type, source and super options.
+            let (mount, filesystem) = line.split_once(" - ")?;
+            let mount = mount.split_whitespace().collect::<Vec<_>>();
+            let filesystem = filesystem.split_whitespace().collect::<Vec<_>>();
+            let options = |options: &str| options.split(',').map(|option| option.to_string()).collect();
+
+            Some(Mount {
+                root: unescape(mount.get(3)?),
+                mount_point: unescape(mount.get(4)?),
+                options: options(mount.get(5)?),
+                fs_type: filesystem.first()?.to_string(),
+                source: unescape(filesystem.get(1)?),
+                super_options: options(filesystem.get(2)?),
+            })
+        })
+        .collect()
+}
+
+/// Undo the octal escapes of spaces, tabs, newlines and backslashes, e.g. "\040".
+fn unescape(field: &str) -> String {
+    field.replace("\\040", " ").replace("\\011", "\t").replace("\\012", "\n").replace("\\134", "\\")
+}
+
+/// Map the devices linked from a /dev/disk/by-uuid or by-label directory to their link names.
+/// udev escapes unsafe characters of labels as hexadecimal, e.g. "My\x20Disk".
+pub fn read_disk_links(directory: &Path) -> HashMap<PathBuf, String> {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return HashMap::new();
+    };
+
+    entries.flatten()
+        .filter_map(|entry| {
+            let device = fs::canonicalize(entry.path()).ok()?;
+            Some((device, unescape_hex(&entry.file_name().to_string_lossy())))
+        })
+        .collect()
+}
+
+fn unescape_hex(name: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut rest = name.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        let escaped = tail.strip_prefix(b"x")
+            .filter(|_| byte == b'\\')
+            .and_then(|hex| std::str::from_utf8(hex.get(..2)?).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(escaped) => {
+                bytes.push(escaped);
+                rest = &tail[3..];
+            }
+            None => {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).to_string()
+}
+
+/// Compression of a ZFS dataset, e.g. "compression=lz4", which mountinfo doesn't list.
+pub fn zfs_compression(dataset: &str) -> Option<String> {
+    let output = output_with_timeout(
+        Command::new("zfs").args(["get", "-H", "-o", "value", "compression", dataset]),
+        Duration::from_secs(1),
+    )?;
+
+    match output.trim() {
+        "" | "off" | "-" => None,
+        compression => Some(format!("compression={}", compression)),
+    }
+}
+
+/// Total and free inodes of the filesystem mounted at `mount_point`. Filesystems allocating
+/// inodes dynamically, such as btrfs, report no total.
+#[cfg(unix)]
+pub fn inodes(mount_point: &str) -> Option<(u64, u64)> {
+    use std::ffi::CString;
+    use std::mem::MaybeUninit;
+
+    let path = CString::new(mount_point).ok()?;
+    let mut stat = MaybeUninit::<libc::statvfs>::uninit();
+    // SAFETY: path is NUL terminated and stat is only read once statvfs filled it.
+    let stat = unsafe {
+        if libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) != 0 {
+            return None;
+        }
+        stat.assume_init()
+    };
+
+    match stat.f_files {
+        0 => None,
+        #[allow(clippy::unnecessary_cast)]
+        files => Some((files as u64, stat.f_ffree as u64)),
+    }
+}
+
+#[cfg(not(unix))]
+pub fn inodes(_mount_point: &str) -> Option<(u64, u64)> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::mountinfo::{parse_mountinfo, unescape_hex};
+    use crate::utils::sysfs::fake_tree;
+
+    #[test]
+    fn parses_mountinfo() {
+        let mounts = parse_mountinfo("\
+29 1 259:2 / / rw,noatime shared:1 - btrfs /dev/nvme0n1p2 rw,compress=zstd:3,ssd,space_cache=v2,subvolid=256,subvol=/@
+41 29 259:2 /@home /home\\040dir rw,relatime shared:30 - btrfs /dev/nvme0n1p2 rw,compress=zstd:3,ssd,subvolid=257,subvol=/@home
+");
+
+        assert_eq!(mounts.len(), 2);
+        assert_eq!(mounts[0].options, vec!["rw", "noatime"]);
+        assert_eq!(mounts[0].source, "/dev/nvme0n1p2");
+        assert!(mounts[0].super_options.contains(&"compress=zstd:3".to_string()));
+        assert_eq!(mounts[1].root, "/@home");
+        assert_eq!(mounts[1].mount_point, "/home dir");
+    }
+
+    #[test]
+    fn unescapes_labels() {
+        assert_eq!(unescape_hex("My\\x20Disk"), "My Disk");
+        assert_eq!(unescape_hex("EFI\\x2fboot\\x"), "EFI/boot\\x");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn reads_disk_links() {
+        use std::fs;
+        use std::os::unix::fs::symlink;
+        use crate::system_info::mountinfo::read_disk_links;
+
+        let root = fake_tree("mountinfo-links", &[("dev/nvme0n1p2", ""), ("dev/disk/by-label/.keep", "")]);
+        symlink("../../nvme0n1p2", root.join("dev/disk/by-label/Fedora\\x20Root")).unwrap();
+        fs::remove_file(root.join("dev/disk/by-label/.keep")).unwrap();
+
+        let labels = read_disk_links(&root.join("dev/disk/by-label"));
+        assert_eq!(labels.get(&fs::canonicalize(root.join("dev/nvme0n1p2")).unwrap()).map(String::as_str), Some("Fedora Root"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn reads_inodes() {
+        use crate::system_info::mountinfo::inodes;
+
+        // Some filesystems, e.g. btrfs, report no inode total.
+        if let Some((total, free)) = inodes("/") {
+            assert!(free <= total);
+        }
+        assert_eq!(inodes("/nonexistent/mount"), None);
+    }
+}
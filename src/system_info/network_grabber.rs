@@ -0,0 +1,201 @@
+use std::collections::HashSet;
+use std::fs;
+use std::net::IpAddr;
+use std::path::Path;
+use sysinfo::{Networks, System};
+use crate::cli::args::Cli;
+use crate::system::component::Component;
+use crate::system::network::{DnsInfo, NetworkInfo};
+use crate::system_info::grabber::info_grab::Grabber;
+use crate::system_info::throughput;
+use crate::utils::error::WMIError;
+use crate::utils::sysfs::{read_number, read_string};
+
+const NET_CLASS_PATH: &str = "/sys/class/net";
+const IPV4_ROUTE_PATH: &str = "/proc/net/route";
+const IPV6_ROUTE_PATH: &str = "/proc/net/ipv6_route";
+const RESOLV_CONF_PATH: &str = "/etc/resolv.conf";
+
+// Interface type of loopback devices, see include/uapi/linux/if_arp.h
+const ARPHRD_LOOPBACK: u32 = 772;
+
+pub struct NetworkGrabber;
+
+impl Grabber for NetworkGrabber {
+    fn grab(_sys: &System, cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        let mut network_info = Vec::new();
+
+        let default_routes = parse_default_routes(
+            &fs::read_to_string(IPV4_ROUTE_PATH).unwrap_or_default(),
+            &fs::read_to_string(IPV6_ROUTE_PATH).unwrap_or_default(),
+        );
+        let networks = Networks::new_with_refreshed_list();
+        let throughput = throughput::sample(cli);
+
+        for mut interface in read_interfaces(Path::new(NET_CLASS_PATH)) {
+            if cli.hide_virtual && (interface.is_loopback || interface.is_virtual) {
+                continue;
+            }
+
+            // sysfs doesn't expose addresses, sysinfo collects them with getifaddrs.
+            if let Some(data) = networks.list().get(&interface.name) {
+                for network in data.ip_networks() {
+                    match network.addr {
+                        IpAddr::V4(addr) => interface.ipv4.push(format!("{}/{}", addr, network.prefix)),
+                        IpAddr::V6(addr) => interface.ipv6.push(format!("{}/{}", addr, network.prefix)),
+                    }
+                }
+            }
+            interface.is_default_route = default_routes.contains(&interface.name);
+            interface.throughput = throughput.and_then(|throughput| throughput.networks.get(&interface.name).cloned());
+
+            network_info.push(Box::new(interface) as Box<dyn Component>);
+        }
+
+        let dns_servers = parse_dns_servers(&fs::read_to_string(RESOLV_CONF_PATH).unwrap_or_default());
+        if !dns_servers.is_empty() {
+            network_info.push(Box::new(DnsInfo::new(dns_servers)) as Box<dyn Component>);
+        }
+
+        Ok(network_info)
+    }
+}
+
+fn read_interfaces(root: &Path) -> Vec<NetworkInfo> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+
+    let mut interfaces = entries.flatten().map(|entry| read_interface(&entry.path())).collect::<Vec<_>>();
+    interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+
+    interfaces
+}
+
+fn read_interface(path: &Path) -> NetworkInfo {
+    // Entries of /sys/class/net link to /sys/devices/virtual/net/* for software interfaces.
+    let is_virtual = fs::read_link(path)
+        .map(|target| target.to_string_lossy().contains("/virtual/"))
+        .unwrap_or(false);
+
+    NetworkInfo {
+        name: path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default(),
+        state: read_string(&path.join("operstate")),
+        mac_address: read_string(&path.join("address")).filter(|address| address != "00:00:00:00:00:00"),
+        mtu: read_number(&path.join("mtu")),
+        // Reading speed fails or yields -1 when the link is down or the driver doesn't know.
+        speed: read_number::<i64>(&path.join("speed")).filter(|speed| *speed > 0).map(|speed| speed as u64),
+        duplex: read_string(&path.join("duplex")).filter(|duplex| duplex != "unknown"),
+        is_loopback: read_number::<u32>(&path.join("type")) == Some(ARPHRD_LOOPBACK),
+        is_virtual,
+        ..Default::default()
+    }
+}
+
+/// Names of the interfaces holding an IPv4 or IPv6 default route.
+fn parse_default_routes(ipv4_route: &str, ipv6_route: &str) -> HashSet<String> {
+    let mut interfaces = HashSet::new();
+
+    // Iface Destination Gateway Flags RefCnt Use Metric Mask ...
+    for line in ipv4_route.lines().skip(1) {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        if fields.len() < 8 || fields[1] != "00000000" || fields[7] != "00000000" {
+            continue;
+        }
+
+        // Only routes in use (RTF_UP).
+        let flags = u32::from_str_radix(fields[3], 16).unwrap_or(0);
+        if flags & 0x0001 != 0 {
+            interfaces.insert(fields[0].to_string());
+        }
+    }
+
+    // Destination PrefixLength Source SourcePrefixLength NextHop Metric RefCnt Use Flags Iface
+    for line in ipv6_route.lines() {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        if fields.len() < 10 || fields[0].chars().any(|c| c != '0') || fields[1] != "00" {
+            continue;
+        }
+
+        // Skip unreachable default routes which the kernel puts on lo (RTF_REJECT).
+        let flags = u32::from_str_radix(fields[8], 16).unwrap_or(0);
+        if flags & 0x0001 != 0 && flags & 0x0200 == 0 {
+            interfaces.insert(fields[9].to_string());
+        }
+    }
+
+    interfaces
+}
+
+fn parse_dns_servers(resolv_conf: &str) -> Vec<String> {
+    resolv_conf.lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            fields.next().filter(|keyword| *keyword == "nameserver")?;
+            fields.next()
+        })
+        .map(|server| server.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::network_grabber::{parse_default_routes, parse_dns_servers, read_interfaces};
+    use crate::utils::sysfs::fake_tree;
+
+    #[test]
+    fn reads_interfaces() {
+        let root = fake_tree("network-interfaces", &[
+            ("eth0/operstate", "up\n"),
+            ("eth0/address", "52:54:00:12:34:56\n"),
+            ("eth0/mtu", "1500\n"),
+            ("eth0/speed", "1000\n"),
+            ("eth0/duplex", "full\n"),
+            ("eth0/type", "1\n"),
+            ("lo/operstate", "unknown\n"),
+            ("lo/address", "00:00:00:00:00:00\n"),
+            ("lo/mtu", "65536\n"),
+            ("lo/speed", "-1\n"),
+            ("lo/type", "772\n"),
+        ]);
+
+        let interfaces = read_interfaces(&root);
+        assert_eq!(interfaces.len(), 2);
+
+        let (eth0, lo) = (&interfaces[0], &interfaces[1]);
+        assert_eq!(eth0.name, "eth0");
+        assert_eq!(eth0.speed, Some(1000));
+        assert_eq!(eth0.mtu, Some(1500));
+        assert!(!eth0.is_loopback);
+        assert_eq!(lo.mac_address, None);
+        assert_eq!(lo.speed, None);
+        assert!(lo.is_loopback);
+    }
+
+    #[test]
+    fn parses_default_routes() {
+        let ipv4_route = "\
+Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT
+eth0\t00000000\t010200C0\t0003\t0\t0\t0\t00000000\t0\t0\t0
+eth0\t000200C0\t00000000\t0001\t0\t0\t0\t00FFFFFF\t0\t0\t0
+wwan0\t00000000\t0100000A\t0002\t0\t0\t0\t00000000\t0\t0\t0
+";
+        let ipv6_route = "\
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 fd000000000000000000000000000001 00000400 00000001 00000000 00000003     wlan0
+fe800000000000000000000000000000 40 00000000000000000000000000000000 00 00000000000000000000000000000000 00000100 00000002 00000000 00000001     eth0
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 00000000000000000000000000000000 ffffffff 00000001 00000000 00200200       lo
+";
+
+        let interfaces = parse_default_routes(ipv4_route, ipv6_route);
+        assert_eq!(interfaces.len(), 2);
+        assert!(interfaces.contains("eth0"));
+        assert!(interfaces.contains("wlan0"));
+    }
+
+    #[test]
+    fn parses_dns_servers() {
+        let resolv_conf = "# Generated by NetworkManager\nsearch lan\nnameserver 192.168.1.1\nnameserverX 10.0.0.1\nnameserver fe80::1%wlan0\noptions edns0\n";
+
+        assert_eq!(parse_dns_servers(resolv_conf), vec!["192.168.1.1", "fe80::1%wlan0"]);
+    }
+}
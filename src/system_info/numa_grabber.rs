@@ -0,0 +1,145 @@
+use std::fs;
+use std::path::Path;
+use sysinfo::System;
+use crate::cli::args::Cli;
+use crate::system::component::Component;
+use crate::system::numa::{NumaInfo, NumaNode, PciDevice};
+use crate::system_info::grabber::info_grab::Grabber;
+use crate::utils::error::WMIError;
+use crate::utils::sysfs::{parse_cpu_list, read_number, read_string};
+
+const NODE_PATH: &str = "/sys/devices/system/node";
+const PCI_DEVICES_PATH: &str = "/sys/bus/pci/devices";
+
+// PCI base classes worth placing on a node.
+const PCI_CLASSES: [(u32, &str); 4] = [(0x01, "Storage"), (0x02, "Network"), (0x03, "Display"), (0x12, "Accelerator")];
+
+pub struct NumaGrabber;
+
+impl Grabber for NumaGrabber {
+    fn grab(_sys: &System, _cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        let nodes = read_nodes(Path::new(NODE_PATH), Path::new(PCI_DEVICES_PATH));
+
+        // Single node machines have no NUMA layout worth showing.
+        if nodes.len() < 2 {
+            return Ok(Vec::new());
+        }
+
+        Ok(vec![Box::new(NumaInfo { nodes }) as Box<dyn Component>])
+    }
+}
+
+fn read_nodes(node_root: &Path, pci_root: &Path) -> Vec<NumaNode> {
+    let Ok(entries) = fs::read_dir(node_root) else {
+        return Vec::new();
+    };
+
+    let devices = read_pci_devices(pci_root);
+
+    let mut nodes = entries.flatten()
+        .filter_map(|entry| {
+            let id = entry.file_name().to_string_lossy().strip_prefix("node")?.parse::<usize>().ok()?;
+            let node = entry.path();
+            let meminfo = read_string(&node.join("meminfo")).unwrap_or_default();
+
+            Some(NumaNode {
+                id,
+                cpus: parse_cpu_list(&read_string(&node.join("cpulist")).unwrap_or_default()),
+                memory_total: parse_node_meminfo(&meminfo, "MemTotal").unwrap_or(0),
+                memory_free: parse_node_meminfo(&meminfo, "MemFree").unwrap_or(0),
+                distances: read_string(&node.join("distance")).unwrap_or_default()
+                    .split_whitespace()
+                    .filter_map(|distance| distance.parse().ok())
+                    .collect(),
+                devices: devices.iter()
+                    .filter(|(device_node, _)| *device_node == id as i64)
+                    .map(|(_, device)| device.clone())
+                    .collect(),
+            })
+        })
+        .collect::<Vec<_>>();
+    nodes.sort_by_key(|node| node.id);
+
+    nodes
+}
+
+/// Read a field of a node meminfo, e.g. "Node 0 MemTotal:  131072000 kB", in bytes.
+fn parse_node_meminfo(meminfo: &str, key: &str) -> Option<u64> {
+    meminfo.lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.split_whitespace().last() == Some(key))
+        .and_then(|(_, value)| value.split_whitespace().next()?.parse::<u64>().ok())
+        .map(|kilobytes| kilobytes * 1024)
+}
+
+/// PCI devices of interesting classes with the node they are attached to, -1 when unknown.
+fn read_pci_devices(pci_root: &Path) -> Vec<(i64, PciDevice)> {
+    let Ok(entries) = fs::read_dir(pci_root) else {
+        return Vec::new();
+    };
+
+    let mut devices = entries.flatten()
+        .filter_map(|entry| {
+            let device = entry.path();
+            // The class is 0xCCSSPP: base class, subclass and programming interface.
+            let class = read_string(&device.join("class"))?;
+            let class = u32::from_str_radix(class.trim_start_matches("0x"), 16).ok()? >> 16;
+            let (_, class) = PCI_CLASSES.iter().find(|(code, _)| *code == class)?;
+
+            let interfaces = match fs::read_dir(device.join("net")) {
+                Ok(interfaces) => interfaces.flatten().map(|interface| interface.file_name().to_string_lossy().to_string()).collect(),
+                Err(_) => Vec::new(),
+            };
+
+            Some((read_number::<i64>(&device.join("numa_node")).unwrap_or(-1), PciDevice {
+                address: entry.file_name().to_string_lossy().to_string(),
+                class: class.to_string(),
+                driver: fs::read_link(device.join("driver")).ok()
+                    .and_then(|driver| driver.file_name().map(|name| name.to_string_lossy().to_string())),
+                interfaces,
+            }))
+        })
+        .collect::<Vec<_>>();
+    devices.sort_by(|a, b| a.1.address.cmp(&b.1.address));
+
+    devices
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::numa_grabber::read_nodes;
+    use crate::utils::sysfs::fake_tree;
+
+    #[test]
+    fn reads_two_nodes() {
+        let root = fake_tree("numa-nodes", &[
+            ("node/node0/cpulist", "0-27,56-83\n"),
+            ("node/node0/distance", "10 21\n"),
+            ("node/node0/meminfo", "Node 0 MemTotal:       131072000 kB\nNode 0 MemFree:        104857600 kB\nNode 0 MemUsed:         26214400 kB\n"),
+            ("node/node1/cpulist", "28-55,84-111\n"),
+            ("node/node1/distance", "21 10\n"),
+            ("node/node1/meminfo", "Node 1 MemTotal:       131072000 kB\nNode 1 MemFree:        130000000 kB\n"),
+            ("node/has_cpu", "0-1\n"),
+            ("pci/0000:17:00.0/class", "0x020000\n"),
+            ("pci/0000:17:00.0/numa_node", "0\n"),
+            ("pci/0000:17:00.0/net/ens1f0/mtu", "1500\n"),
+            ("pci/0000:b1:00.0/class", "0x030200\n"),
+            ("pci/0000:b1:00.0/numa_node", "1\n"),
+            ("pci/0000:00:1f.0/class", "0x060100\n"),
+            ("pci/0000:00:1f.0/numa_node", "0\n"),
+        ]);
+
+        let nodes = read_nodes(&root.join("node"), &root.join("pci"));
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[0].cpus.len(), 56);
+        assert_eq!(nodes[0].memory_total, 131072000 * 1024);
+        assert_eq!(nodes[0].memory_free, 104857600 * 1024);
+        assert_eq!(nodes[1].distances, vec![21, 10]);
+
+        assert_eq!(nodes[0].devices.len(), 1);
+        assert_eq!(nodes[0].devices[0].class, "Network");
+        assert_eq!(nodes[0].devices[0].interfaces, vec!["ens1f0"]);
+        assert_eq!(nodes[1].devices[0].address, "0000:b1:00.0");
+        assert_eq!(nodes[1].devices[0].class, "Display");
+    }
+}
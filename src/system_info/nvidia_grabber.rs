@@ -1,7 +1,8 @@
 use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
 use nvml_wrapper::Nvml;
 use sysinfo::System;
-use wmi::WMIError;
+use crate::cli::args::Cli;
+use crate::utils::error::WMIError;
 use crate::system::component::Component;
 use crate::system::nvidia_gpu::NvidiaGpuInfo;
 use crate::system_info::grabber::info_grab::Grabber;
@@ -9,7 +10,7 @@ use crate::system_info::grabber::info_grab::Grabber;
 pub struct NvidiaGrabber;
 
 impl Grabber for NvidiaGrabber {
-    fn grab(_sys: &System) -> Result<Vec<Box<dyn Component>>, WMIError> {
+    fn grab(_sys: &System, _cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
         let mut nvidia_gpu_info = Vec::new();
 
         match Nvml::init() {
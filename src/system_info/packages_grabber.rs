@@ -0,0 +1,201 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde_json::Value;
+use sysinfo::System;
+use crate::cli::args::{Cli, PackageManager};
+use crate::system::component::Component;
+use crate::system::packages::{PackageCount, PackagesInfo};
+use crate::system_info::grabber::info_grab::Grabber;
+use crate::utils::error::WMIError;
+use crate::utils::sqlite;
+
+const ALL_MANAGERS: [PackageManager; 9] = [
+    PackageManager::Dpkg,
+    PackageManager::Rpm,
+    PackageManager::Pacman,
+    PackageManager::Apk,
+    PackageManager::Flatpak,
+    PackageManager::Snap,
+    PackageManager::Nix,
+    PackageManager::Brew,
+    PackageManager::Cargo,
+];
+
+pub struct PackagesGrabber;
+
+impl Grabber for PackagesGrabber {
+    fn grab(_sys: &System, cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        let home = env::var_os("HOME").map(PathBuf::from);
+        let cargo_home = env::var_os("CARGO_HOME").map(PathBuf::from)
+            .or_else(|| home.as_ref().map(|home| home.join(".cargo")));
+
+        let counts = ALL_MANAGERS.iter()
+            .filter(|manager| !cli.hide_packages.contains(manager))
+            .flat_map(|manager| count_packages(*manager, Path::new("/"), home.as_deref(), cargo_home.as_deref()))
+            .collect::<Vec<_>>();
+
+        if counts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(vec![Box::new(PackagesInfo::new(counts)) as Box<dyn Component>])
+    }
+}
+
+/// Count installed packages by reading the package manager's local database, without running it.
+fn count_packages(manager: PackageManager, root: &Path, home: Option<&Path>, cargo_home: Option<&Path>) -> Vec<PackageCount> {
+    let counts = match manager {
+        PackageManager::Dpkg => vec![("dpkg", count_dpkg(&root.join("var/lib/dpkg/status")))],
+        PackageManager::Rpm => vec![("rpm", sqlite::count_rows(&root.join("var/lib/rpm/rpmdb.sqlite"), "Packages"))],
+        PackageManager::Pacman => vec![("pacman", count_dirs(&root.join("var/lib/pacman/local")))],
+        PackageManager::Apk => vec![("apk", count_apk(&root.join("lib/apk/db/installed")))],
+        PackageManager::Flatpak => {
+            let mut installations = vec![root.join("var/lib/flatpak")];
+            installations.extend(home.map(|home| home.join(".local/share/flatpak")));
+
+            vec![("flatpak", sum(installations.iter().map(|installation| count_flatpak(installation))))]
+        }
+        PackageManager::Snap => vec![("snap", count_snap(&root.join("snap")))],
+        PackageManager::Nix => {
+            let mut profiles = vec![("nix-default", count_nix_profile(&root.join("nix/var/nix/profiles/default")))];
+            profiles.extend(home.map(|home| ("nix-user", count_nix_profile(&home.join(".nix-profile")))));
+
+            profiles
+        }
+        PackageManager::Brew => {
+            let prefixes = ["home/linuxbrew/.linuxbrew", "opt/homebrew", "usr/local"].map(|prefix| root.join(prefix));
+
+            vec![
+                ("brew", sum(prefixes.iter().map(|prefix| count_dirs(&prefix.join("Cellar"))))),
+                ("brew-cask", sum(prefixes.iter().map(|prefix| count_dirs(&prefix.join("Caskroom"))))),
+            ]
+        }
+        PackageManager::Cargo => vec![("cargo", cargo_home.and_then(count_cargo))],
+    };
+
+    counts.into_iter()
+        .filter_map(|(manager, count)| count.filter(|count| *count > 0).map(|count| PackageCount { manager: manager.to_string(), count }))
+        .collect()
+}
+
+fn sum(counts: impl Iterator<Item = Option<usize>>) -> Option<usize> {
+    counts.fold(None, |total, count| match (total, count) {
+        (total, None) => total,
+        (total, Some(count)) => Some(total.unwrap_or(0) + count),
+    })
+}
+
+fn count_dirs(path: &Path) -> Option<usize> {
+    let entries = fs::read_dir(path).ok()?;
+
+    Some(entries.flatten().filter(|entry| entry.path().is_dir()).count())
+}
+
+fn count_dpkg(status: &Path) -> Option<usize> {
+    let content = fs::read_to_string(status).ok()?;
+
+    // The first word is the selection, e.g. "hold". Removed packages keep their paragraph with
+    // another state, e.g. "deinstall ok config-files".
+    Some(content.lines().filter(|line| line.starts_with("Status: ") && line.ends_with(" ok installed")).count())
+}
+
+fn count_apk(installed: &Path) -> Option<usize> {
+    let content = fs::read_to_string(installed).ok()?;
+
+    Some(content.lines().filter(|line| line.starts_with("P:")).count())
+}
+
+fn count_flatpak(installation: &Path) -> Option<usize> {
+    // Applications and runtimes are laid out as <kind>/<name>/<arch>/<branch>.
+    let refs = ["app", "runtime"].iter()
+        .filter_map(|kind| fs::read_dir(installation.join(kind)).ok())
+        .flatten()
+        .flatten()
+        .filter_map(|name| fs::read_dir(name.path()).ok())
+        .flatten()
+        .flatten()
+        .filter(|arch| arch.file_name() != "current" && arch.path().is_dir())
+        .filter_map(|arch| count_dirs(&arch.path()))
+        .sum::<usize>();
+
+    installation.exists().then_some(refs)
+}
+
+fn count_snap(snap: &Path) -> Option<usize> {
+    let entries = fs::read_dir(snap).ok()?;
+
+    Some(entries.flatten()
+        .filter(|entry| entry.file_name() != "bin" && entry.path().is_dir())
+        .count())
+}
+
+fn count_nix_profile(profile: &Path) -> Option<usize> {
+    // `nix profile` writes manifest.json, `nix-env` writes manifest.nix.
+    if let Ok(content) = fs::read_to_string(profile.join("manifest.json")) {
+        return match serde_json::from_str::<Value>(&content).ok()?.get("elements")? {
+            Value::Array(elements) => Some(elements.len()),
+            Value::Object(elements) => Some(elements.len()),
+            _ => None,
+        };
+    }
+
+    let content = fs::read_to_string(profile.join("manifest.nix")).ok()?;
+
+    Some(content.matches("type = \"derivation\"").count())
+}
+
+fn count_cargo(cargo_home: &Path) -> Option<usize> {
+    let content = fs::read_to_string(cargo_home.join(".crates2.json")).ok()?;
+    let installs = serde_json::from_str::<Value>(&content).ok()?;
+
+    Some(installs.get("installs")?.as_object()?.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::args::PackageManager;
+    use crate::system_info::packages_grabber::count_packages;
+    use crate::utils::sysfs::fake_tree;
+
+    fn count(manager: PackageManager, root: &std::path::Path) -> Vec<(String, usize)> {
+        count_packages(manager, root, Some(&root.join("home/user")), Some(&root.join("home/user/.cargo")))
+            .into_iter()
+            .map(|count| (count.manager, count.count))
+            .collect()
+    }
+
+    #[test]
+    fn counts_packages() {
+        let root = fake_tree("packages", &[
+            ("var/lib/dpkg/status", "Package: bash\nStatus: install ok installed\n\nPackage: vim\nStatus: deinstall ok config-files\n\nPackage: zsh\nStatus: install ok installed\n\nPackage: linux-image-generic\nStatus: hold ok installed\n"),
+            ("var/lib/pacman/local/ALPM_DB_VERSION", "9\n"),
+            ("var/lib/pacman/local/bash-5.2.026-2/desc", ""),
+            ("var/lib/pacman/local/glibc-2.39-1/desc", ""),
+            ("lib/apk/db/installed", "C:Q1\nP:musl\nV:1.2.5\n\nC:Q2\nP:busybox\nV:1.36.1\n"),
+            ("var/lib/flatpak/app/org.mozilla.firefox/current", ""),
+            ("var/lib/flatpak/app/org.mozilla.firefox/x86_64/stable/active/metadata", ""),
+            ("var/lib/flatpak/runtime/org.freedesktop.Platform/x86_64/23.08/active/metadata", ""),
+            ("var/lib/flatpak/runtime/org.freedesktop.Platform/x86_64/24.08/active/metadata", ""),
+            ("home/user/.local/share/flatpak/app/org.gimp.GIMP/x86_64/stable/active/metadata", ""),
+            ("snap/bin/firefox", ""),
+            ("snap/core22/1380/meta", ""),
+            ("snap/firefox/4173/meta", ""),
+            ("snap/README", ""),
+            ("home/user/.nix-profile/manifest.json", "{\"elements\": {\"hello\": {}, \"ripgrep\": {}}, \"version\": 3}"),
+            ("nix/var/nix/profiles/default/manifest.nix", "[ { meta = { }; name = \"nix-2.18\"; type = \"derivation\"; } ]"),
+            ("home/linuxbrew/.linuxbrew/Cellar/git/2.45.0/bin/git", ""),
+            ("home/user/.cargo/.crates2.json", "{\"installs\": {\"ripgrep 14.1.0 (registry+https://github.com/rust-lang/crates.io-index)\": {}}}"),
+        ]);
+
+        assert_eq!(count(PackageManager::Dpkg, &root), vec![("dpkg".to_string(), 3)]);
+        assert_eq!(count(PackageManager::Pacman, &root), vec![("pacman".to_string(), 2)]);
+        assert_eq!(count(PackageManager::Apk, &root), vec![("apk".to_string(), 2)]);
+        assert_eq!(count(PackageManager::Flatpak, &root), vec![("flatpak".to_string(), 4)]);
+        assert_eq!(count(PackageManager::Snap, &root), vec![("snap".to_string(), 2)]);
+        assert_eq!(count(PackageManager::Nix, &root), vec![("nix-default".to_string(), 1), ("nix-user".to_string(), 2)]);
+        assert_eq!(count(PackageManager::Brew, &root), vec![("brew".to_string(), 1)]);
+        assert_eq!(count(PackageManager::Cargo, &root), vec![("cargo".to_string(), 1)]);
+        assert!(count(PackageManager::Rpm, &root).is_empty());
+    }
+}
@@ -0,0 +1,173 @@
+use std::fs;
+use std::path::Path;
+use sysinfo::System;
+use crate::cli::args::Cli;
+use crate::system::component::Component;
+use crate::system::security::{AppArmorProfiles, SecurityInfo};
+use crate::system_info::grabber::info_grab::Grabber;
+use crate::utils::error::WMIError;
+use crate::utils::sysfs::{read_string, selected};
+
+const SYS_PATH: &str = "/sys";
+
+// EFI global variable GUID, see the UEFI specification section 3.3.
+const EFI_GLOBAL_VARIABLE: &str = "8be4df61-93ca-11d2-aa0d-00e098032b8c";
+
+pub struct SecurityGrabber;
+
+impl Grabber for SecurityGrabber {
+    fn grab(_sys: &System, _cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        // Everything comes from sysfs, other platforms get nothing.
+        if !Path::new(SYS_PATH).join("kernel").exists() {
+            return Ok(Vec::new());
+        }
+
+        Ok(vec![Box::new(read_security(Path::new(SYS_PATH))) as Box<dyn Component>])
+    }
+}
+
+fn read_security(sys: &Path) -> SecurityInfo {
+    let efi = sys.join("firmware/efi");
+    let boot_mode = match (sys.join("firmware").exists(), efi.exists()) {
+        (_, true) => Some("UEFI"),
+        (true, false) => Some("BIOS"),
+        (false, false) => None,
+    };
+
+    // Legacy BIOS has no Secure Boot at all.
+    let (secure_boot, setup_mode) = match boot_mode {
+        Some("BIOS") => (Some(false), None),
+        _ => (read_efi_flag(&efi, "SecureBoot"), read_efi_flag(&efi, "SetupMode")),
+    };
+
+    let security_modules = read_string(&sys.join("kernel/security/lsm"))
+        .map(|lsm| lsm.split(',').map(|module| module.to_string()).collect::<Vec<_>>());
+    let lsm_inactive = |name: &str| security_modules.as_ref().is_some_and(|modules| !modules.iter().any(|module| module == name));
+
+    let selinux = match read_string(&sys.join("fs/selinux/enforce")).as_deref() {
+        Some("1") => Some("enforcing"),
+        Some("0") => Some("permissive"),
+        _ if lsm_inactive("selinux") => Some("disabled"),
+        _ => None,
+    };
+
+    let apparmor = match read_string(&sys.join("module/apparmor/parameters/enabled")).as_deref() {
+        Some("Y") => Some("enabled"),
+        Some("N") => Some("disabled"),
+        _ if lsm_inactive("apparmor") => Some("disabled"),
+        _ => None,
+    };
+
+    SecurityInfo {
+        boot_mode: boot_mode.map(|mode| mode.to_string()),
+        secure_boot,
+        setup_mode,
+        tpm_version: read_tpm_version(&sys.join("class/tpm"), &sys.join("class")),
+        security_modules,
+        selinux: selinux.map(|mode| mode.to_string()),
+        apparmor: apparmor.map(|state| state.to_string()),
+        // Profiles are only readable by root.
+        apparmor_profiles: fs::read_to_string(sys.join("kernel/security/apparmor/profiles")).ok()
+            .map(|profiles| AppArmorProfiles {
+                enforce: profiles.lines().filter(|line| line.ends_with("(enforce)")).count(),
+                complain: profiles.lines().filter(|line| line.ends_with("(complain)")).count(),
+            }),
+        lockdown: read_string(&sys.join("kernel/security/lockdown")).and_then(|lockdown| selected(&lockdown)),
+    }
+}
+
+/// Read a boolean EFI global variable. efivarfs prefixes the value with 4 bytes of attributes.
+fn read_efi_flag(efi: &Path, name: &str) -> Option<bool> {
+    let variable = fs::read(efi.join("efivars").join(format!("{}-{}", name, EFI_GLOBAL_VARIABLE))).ok()?;
+
+    variable.get(4).map(|value| *value == 1)
+}
+
+fn read_tpm_version(tpm_class: &Path, class: &Path) -> Option<String> {
+    // Without the tpm class there is no TPM driver bound, as long as sysfs itself is readable.
+    if !tpm_class.exists() {
+        return class.exists().then(|| "none".to_string());
+    }
+
+    let tpm = tpm_class.join("tpm0");
+    if !tpm.exists() {
+        return Some("none".to_string());
+    }
+
+    match read_string(&tpm.join("tpm_version_major")).as_deref() {
+        Some("2") => return Some("2.0".to_string()),
+        Some("1") => return Some("1.2".to_string()),
+        _ => {}
+    }
+
+    // Kernels before 5.5 only expose capabilities for TPM 1.2 chips, e.g. "TCG version: 1.2".
+    let caps = read_string(&tpm.join("caps")).or_else(|| read_string(&tpm.join("device/caps")));
+    match caps {
+        Some(caps) => caps.lines()
+            .find_map(|line| line.strip_prefix("TCG version: "))
+            .map(|version| version.trim().to_string()),
+        None => Some("2.0".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::security_grabber::read_security;
+    use crate::utils::sysfs::fake_tree;
+
+    #[test]
+    fn reads_uefi_machine() {
+        let root = fake_tree("security-uefi", &[
+            ("firmware/efi/efivars/SecureBoot-8be4df61-93ca-11d2-aa0d-00e098032b8c", "\x06\0\0\0\x01"),
+            ("firmware/efi/efivars/SetupMode-8be4df61-93ca-11d2-aa0d-00e098032b8c", "\x06\0\0\0\0"),
+            ("class/tpm/tpm0/tpm_version_major", "2\n"),
+            ("kernel/security/lsm", "capability,landlock,lockdown,yama,apparmor,bpf"),
+            ("kernel/security/lockdown", "none [integrity] confidentiality\n"),
+            ("kernel/security/apparmor/profiles", "firefox (enforce)\nsnap.lxd (complain)\n/usr/bin/man (enforce)\n"),
+            ("module/apparmor/parameters/enabled", "Y\n"),
+        ]);
+
+        let security = read_security(&root);
+        assert_eq!(security.boot_mode.as_deref(), Some("UEFI"));
+        assert_eq!(security.secure_boot, Some(true));
+        assert_eq!(security.setup_mode, Some(false));
+        assert_eq!(security.tpm_version.as_deref(), Some("2.0"));
+        assert_eq!(security.security_modules.as_ref().unwrap().len(), 6);
+        assert_eq!(security.selinux.as_deref(), Some("disabled"));
+        assert_eq!(security.apparmor.as_deref(), Some("enabled"));
+        assert_eq!(security.apparmor_profiles.as_ref().map(|profiles| (profiles.enforce, profiles.complain)), Some((2, 1)));
+        assert_eq!(security.lockdown.as_deref(), Some("integrity"));
+    }
+
+    #[test]
+    fn reads_bios_machine_as_user() {
+        // securityfs unmounted and efivars absent: modules are unknown rather than disabled.
+        let root = fake_tree("security-bios", &[
+            ("firmware/acpi/tables/DSDT", ""),
+            ("class/net/lo/mtu", "65536\n"),
+            ("fs/selinux/enforce", "0\n"),
+        ]);
+
+        let security = read_security(&root);
+        assert_eq!(security.boot_mode.as_deref(), Some("BIOS"));
+        assert_eq!(security.secure_boot, Some(false));
+        assert_eq!(security.tpm_version.as_deref(), Some("none"));
+        assert_eq!(security.security_modules, None);
+        assert_eq!(security.selinux.as_deref(), Some("permissive"));
+        assert_eq!(security.apparmor, None);
+        assert_eq!(security.lockdown, None);
+    }
+
+    #[test]
+    fn reads_tpm_12() {
+        let root = fake_tree("security-tpm12", &[
+            ("firmware/efi/systab", ""),
+            ("class/tpm/tpm0/device/caps", "Manufacturer: 0x49465800\nTCG version: 1.2\nFirmware version: 6.40\n"),
+        ]);
+
+        let security = read_security(&root);
+        assert_eq!(security.boot_mode.as_deref(), Some("UEFI"));
+        assert_eq!(security.secure_boot, None);
+        assert_eq!(security.tpm_version.as_deref(), Some("1.2"));
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use sysinfo::{Components, System};
+use crate::cli::args::Cli;
+use crate::system::component::Component;
+use crate::system::sensors::{SensorReading, SensorsInfo};
+use crate::system_info::grabber::info_grab::Grabber;
+use crate::utils::error::WMIError;
+use crate::utils::sysfs::{read_number, read_string};
+
+const HWMON_PATH: &str = "/sys/class/hwmon";
+
+pub struct SensorsGrabber;
+
+impl Grabber for SensorsGrabber {
+    fn grab(_sys: &System, cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        let mut chips = read_hwmon(Path::new(HWMON_PATH));
+
+        // Platforms without hwmon only get the temperatures sysinfo knows about.
+        if chips.is_empty() {
+            chips = read_components();
+        }
+
+        let sensors_info = chips.into_iter()
+            .filter(|chip| cli.sensor_chips.is_empty() || cli.sensor_chips.contains(&chip.chip))
+            .filter(|chip| !chip.temperatures.is_empty() || !chip.fans.is_empty() || !chip.voltages.is_empty())
+            .map(|chip| Box::new(chip) as Box<dyn Component>)
+            .collect();
+
+        Ok(sensors_info)
+    }
+}
+
+fn read_hwmon(root: &Path) -> Vec<SensorsInfo> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+
+    let mut hwmons = entries.flatten().map(|entry| entry.path()).collect::<Vec<_>>();
+    hwmons.sort_by_key(|path| {
+        // Sort hwmon10 after hwmon9.
+        let name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        name.trim_start_matches("hwmon").parse::<u32>().unwrap_or(u32::MAX)
+    });
+
+    hwmons.iter().filter_map(|hwmon| read_chip(hwmon)).collect()
+}
+
+fn read_chip(hwmon: &Path) -> Option<SensorsInfo> {
+    // Older drivers keep their attributes in the device directory.
+    let attributes = match hwmon.join("name").exists() {
+        true => hwmon.to_path_buf(),
+        false => hwmon.join("device"),
+    };
+    let chip = read_string(&attributes.join("name"))?;
+    let device = fs::read_link(hwmon.join("device")).ok()
+        .and_then(|device| device.file_name().map(|name| name.to_string_lossy().to_string()));
+
+    Some(SensorsInfo {
+        chip,
+        device,
+        // Temperatures are in millidegree Celsius, voltages in millivolt, fans in RPM.
+        temperatures: read_readings(&attributes, "temp", 1000f64),
+        fans: read_readings(&attributes, "fan", 1f64),
+        voltages: read_readings(&attributes, "in", 1000f64),
+    })
+}
+
+/// Read every `<prefix><N>_input` of a chip with its label and thresholds, scaled down by `divisor`.
+fn read_readings(attributes: &Path, prefix: &str, divisor: f64) -> Vec<SensorReading> {
+    let Ok(entries) = fs::read_dir(attributes) else {
+        return Vec::new();
+    };
+
+    let indexes = entries.flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            name.strip_prefix(prefix)?.strip_suffix("_input")?.parse::<u32>().ok()
+        })
+        .collect::<BTreeSet<_>>();
+
+    indexes.iter()
+        .filter_map(|index| {
+            let attribute = |name: &str| read_number::<f64>(&attributes.join(format!("{}{}_{}", prefix, index, name)))
+                .map(|value| value / divisor);
+
+            Some(SensorReading {
+                label: read_string(&attributes.join(format!("{}{}_label", prefix, index)))
+                    .unwrap_or_else(|| format!("{}{}", prefix, index)),
+                value: attribute("input")?,
+                // A zero minimum means no alarm is configured.
+                min: attribute("min").filter(|min| *min > 0f64),
+                high: attribute("max"),
+                critical: attribute("crit"),
+            })
+        })
+        .collect()
+}
+
+fn read_components() -> Vec<SensorsInfo> {
+    let components = Components::new_with_refreshed_list();
+
+    let temperatures = components.list().iter()
+        .filter_map(|component| Some(SensorReading {
+            label: component.label().to_string(),
+            value: component.temperature()? as f64,
+            high: component.max().map(|max| max as f64).filter(|max| *max > 0f64),
+            critical: component.critical().map(|critical| critical as f64),
+            ..Default::default()
+        }))
+        .collect::<Vec<_>>();
+
+    vec![SensorsInfo {
+        chip: "system".to_string(),
+        temperatures,
+        ..Default::default()
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::sensors_grabber::read_hwmon;
+    use crate::utils::sysfs::fake_tree;
+
+    #[test]
+    fn reads_hwmon() {
+        let root = fake_tree("sensors-hwmon", &[
+            ("hwmon0/name", "acpitz\n"),
+            ("hwmon0/temp1_input", "27800\n"),
+            ("hwmon0/temp1_crit", "105000\n"),
+            ("hwmon2/name", "coretemp\n"),
+            ("hwmon2/temp1_label", "Package id 0\n"),
+            ("hwmon2/temp1_input", "45000\n"),
+            ("hwmon2/temp1_max", "80000\n"),
+            ("hwmon2/temp1_crit", "100000\n"),
+            ("hwmon2/temp2_label", "Core 0\n"),
+            ("hwmon2/temp2_input", "43000\n"),
+            ("hwmon10/name", "nct6798\n"),
+            ("hwmon10/fan2_input", "1185\n"),
+            ("hwmon10/fan2_min", "0\n"),
+            ("hwmon10/in0_label", "Vcore\n"),
+            ("hwmon10/in0_input", "1032\n"),
+            ("hwmon10/in0_min", "800\n"),
+            ("hwmon10/in0_max", "1740\n"),
+            ("hwmon3/device/name", "it8728\n"),
+            ("hwmon3/device/fan1_input", "900\n"),
+        ]);
+
+        let chips = read_hwmon(&root);
+        assert_eq!(chips.iter().map(|chip| chip.chip.as_str()).collect::<Vec<_>>(), vec!["acpitz", "coretemp", "it8728", "nct6798"]);
+
+        let coretemp = &chips[1];
+        assert_eq!(coretemp.temperatures.len(), 2);
+        assert_eq!(coretemp.temperatures[0].label, "Package id 0");
+        assert_eq!(coretemp.temperatures[0].value, 45.0);
+        assert_eq!(coretemp.temperatures[0].high, Some(80.0));
+        assert_eq!(coretemp.temperatures[0].critical, Some(100.0));
+
+        assert_eq!(chips[0].temperatures[0].label, "temp1");
+        assert_eq!(chips[2].fans[0].value, 900.0);
+
+        let nct = &chips[3];
+        assert_eq!(nct.fans[0].value, 1185.0);
+        assert_eq!(nct.fans[0].min, None);
+        assert_eq!(nct.voltages[0].label, "Vcore");
+        assert_eq!(nct.voltages[0].value, 1.032);
+        assert_eq!(nct.voltages[0].min, Some(0.8));
+    }
+}
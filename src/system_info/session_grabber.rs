@@ -0,0 +1,262 @@
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::Deserialize;
+use sysinfo::System;
+use crate::cli::args::Cli;
+use crate::system::component::Component;
+use crate::system::session::SessionInfo;
+use crate::system_info::grabber::info_grab::Grabber;
+use crate::utils::error::WMIError;
+
+// Detection rules live in a data file, new desktops and window managers only need a new entry there.
+const SESSION_RULES: &str = include_str!("../../assets/session_rules.json");
+
+// Process names in /proc/<pid>/comm are truncated to 15 bytes.
+const COMM_LENGTH: usize = 15;
+
+#[derive(Debug, Deserialize)]
+struct SessionRules {
+    desktops: Vec<DesktopRule>,
+    window_managers: Vec<WindowManagerRule>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DesktopRule {
+    name: String,
+    xdg_names: Vec<String>,     // Values of XDG_CURRENT_DESKTOP
+    sessions: Vec<String>,      // Values of DESKTOP_SESSION
+    processes: Vec<String>,     // Processes only this desktop runs
+}
+
+#[derive(Debug, Deserialize)]
+struct WindowManagerRule {
+    name: String,
+    processes: Vec<String>,
+}
+
+pub struct SessionGrabber;
+
+impl Grabber for SessionGrabber {
+    fn grab(sys: &System, _cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        if cfg!(target_os = "windows") {
+            return Ok(Vec::new());
+        }
+
+        let rules = serde_json::from_str::<SessionRules>(SESSION_RULES)
+            .map_err(|e| WMIError::SerdeError(format!("failed to parse session rules: {}", e)))?;
+        let processes = sys.processes().values()
+            .map(|process| process.name().to_string_lossy().to_string())
+            .collect::<HashSet<_>>();
+        let var = |name: &str| env::var(name).ok().filter(|value| !value.is_empty());
+
+        let mut session = SessionInfo {
+            desktop: detect_desktop(&rules, var("XDG_CURRENT_DESKTOP").as_deref(), var("DESKTOP_SESSION").as_deref(), &processes),
+            window_manager: detect_window_manager(&rules, &processes),
+            session_type: detect_session_type(
+                var("XDG_SESSION_TYPE").as_deref(),
+                var("WAYLAND_DISPLAY").is_some(),
+                var("DISPLAY").is_some(),
+                var("SSH_CONNECTION").is_some(),
+            ),
+            ..Default::default()
+        };
+
+        if let Some(config_dir) = var("XDG_CONFIG_HOME").map(PathBuf::from).or_else(|| var("HOME").map(|home| Path::new(&home).join(".config"))) {
+            read_gtk_settings(&config_dir, &mut session);
+            read_qt_settings(&config_dir, &mut session);
+        }
+
+        Ok(vec![Box::new(session) as Box<dyn Component>])
+    }
+}
+
+fn is_running(processes: &HashSet<String>, name: &str) -> bool {
+    let truncated = name.get(..COMM_LENGTH).unwrap_or(name);
+
+    processes.contains(name) || processes.contains(truncated)
+}
+
+fn detect_desktop(
+    rules: &SessionRules,
+    xdg_current_desktop: Option<&str>,
+    desktop_session: Option<&str>,
+    processes: &HashSet<String>,
+) -> Option<String> {
+    // XDG_CURRENT_DESKTOP is a colon separated list, e.g. "ubuntu:GNOME".
+    if let Some(xdg_current_desktop) = xdg_current_desktop {
+        let names = xdg_current_desktop.split(':').collect::<Vec<_>>();
+        let rule = names.iter().find_map(|name| {
+            rules.desktops.iter().find(|rule| rule.xdg_names.iter().any(|xdg_name| xdg_name.eq_ignore_ascii_case(name)))
+        });
+
+        return Some(rule.map(|rule| rule.name.clone()).unwrap_or_else(|| names[0].to_string()));
+    }
+
+    // DESKTOP_SESSION is sometimes the path of the session file.
+    if let Some(desktop_session) = desktop_session {
+        let session = Path::new(desktop_session).file_stem().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        if let Some(rule) = rules.desktops.iter().find(|rule| rule.sessions.iter().any(|name| name.eq_ignore_ascii_case(&session))) {
+            return Some(rule.name.clone());
+        }
+    }
+
+    rules.desktops.iter()
+        .find(|rule| rule.processes.iter().any(|name| is_running(processes, name)))
+        .map(|rule| rule.name.clone())
+}
+
+fn detect_window_manager(rules: &SessionRules, processes: &HashSet<String>) -> Option<String> {
+    rules.window_managers.iter()
+        .find(|rule| rule.processes.iter().any(|name| is_running(processes, name)))
+        .map(|rule| rule.name.clone())
+}
+
+fn detect_session_type(xdg_session_type: Option<&str>, wayland_display: bool, x11_display: bool, ssh: bool) -> String {
+    match xdg_session_type {
+        Some("wayland") => return "Wayland".to_string(),
+        Some("x11") => return "X11".to_string(),
+        _ => {},
+    }
+
+    match (wayland_display, x11_display, ssh) {
+        (true, _, _) => "Wayland",
+        (false, true, _) => "X11",
+        (false, false, true) => "Headless",
+        (false, false, false) => "TTY",
+    }.to_string()
+}
+
+/// Parse an INI style file into `(section, key) -> value`.
+fn parse_ini(content: &str) -> HashMap<(String, String), String> {
+    let mut section = String::new();
+    let mut values = HashMap::new();
+
+    for line in content.lines().map(|line| line.trim()) {
+        if line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+            section = name.to_string();
+        } else if let Some((key, value)) = line.split_once('=') {
+            let value = value.trim().trim_matches('"');
+            if !value.is_empty() {
+                values.insert((section.clone(), key.trim().to_string()), value.to_string());
+            }
+        }
+    }
+
+    values
+}
+
+fn read_ini(path: &Path) -> HashMap<(String, String), String> {
+    fs::read_to_string(path).map(|content| parse_ini(&content)).unwrap_or_default()
+}
+
+fn read_gtk_settings(config_dir: &Path, session: &mut SessionInfo) {
+    for version in ["gtk-3.0", "gtk-4.0"] {
+        let settings = read_ini(&config_dir.join(version).join("settings.ini"));
+        let setting = |key: &str| settings.get(&("Settings".to_string(), key.to_string())).cloned();
+
+        session.gtk_theme = session.gtk_theme.take().or_else(|| setting("gtk-theme-name"));
+        session.gtk_icon_theme = session.gtk_icon_theme.take().or_else(|| setting("gtk-icon-theme-name"));
+        session.gtk_font = session.gtk_font.take().or_else(|| setting("gtk-font-name"));
+    }
+}
+
+/// Qt stores fonts as "family,point size,..." description strings.
+fn qt_font(description: &str) -> Option<String> {
+    let mut parts = description.split(',');
+    let family = parts.next()?.trim();
+    if family.is_empty() || family.starts_with('@') {
+        return None;
+    }
+
+    match parts.next() {
+        Some(size) => Some(format!("{} {}", family, size.trim())),
+        None => Some(family.to_string()),
+    }
+}
+
+fn read_qt_settings(config_dir: &Path, session: &mut SessionInfo) {
+    let kdeglobals = read_ini(&config_dir.join("kdeglobals"));
+    let kde = |section: &str, key: &str| kdeglobals.get(&(section.to_string(), key.to_string())).cloned();
+
+    session.qt_style = kde("KDE", "widgetStyle").or_else(|| kde("General", "widgetStyle"));
+    session.qt_icon_theme = kde("Icons", "Theme");
+    session.qt_font = kde("General", "font").and_then(|font| qt_font(&font));
+
+    // Outside of Plasma, Qt applications are usually configured with qt5ct / qt6ct.
+    for tool in ["qt6ct", "qt5ct"] {
+        let config = read_ini(&config_dir.join(tool).join(format!("{}.conf", tool)));
+        let setting = |section: &str, key: &str| config.get(&(section.to_string(), key.to_string())).cloned();
+
+        session.qt_style = session.qt_style.take().or_else(|| setting("Appearance", "style"));
+        session.qt_icon_theme = session.qt_icon_theme.take().or_else(|| setting("Appearance", "icon_theme"));
+        session.qt_font = session.qt_font.take().or_else(|| setting("Fonts", "general").and_then(|font| qt_font(&font)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use crate::system::session::SessionInfo;
+    use crate::system_info::session_grabber::{
+        detect_desktop, detect_session_type, detect_window_manager, read_gtk_settings, read_qt_settings,
+        SessionRules, SESSION_RULES,
+    };
+    use crate::utils::sysfs::fake_tree;
+
+    fn rules() -> SessionRules {
+        serde_json::from_str(SESSION_RULES).unwrap()
+    }
+
+    #[test]
+    fn detects_desktop() {
+        let rules = rules();
+        let processes = ["systemd", "plasmashell", "kwin_wayland"].iter().map(|name| name.to_string()).collect::<HashSet<_>>();
+        let no_processes = HashSet::new();
+
+        assert_eq!(detect_desktop(&rules, Some("ubuntu:GNOME"), None, &no_processes).as_deref(), Some("GNOME"));
+        assert_eq!(detect_desktop(&rules, Some("X-Cinnamon"), None, &no_processes).as_deref(), Some("Cinnamon"));
+        assert_eq!(detect_desktop(&rules, Some("Hyprland"), None, &no_processes).as_deref(), Some("Hyprland"));
+        assert_eq!(detect_desktop(&rules, None, Some("/usr/share/xsessions/xfce"), &no_processes).as_deref(), Some("Xfce"));
+        assert_eq!(detect_desktop(&rules, None, None, &processes).as_deref(), Some("KDE Plasma"));
+        assert_eq!(detect_desktop(&rules, None, None, &no_processes), None);
+
+        assert_eq!(detect_window_manager(&rules, &processes).as_deref(), Some("KWin"));
+        // comm truncates io.elementary.wingpanel to 15 bytes.
+        let truncated = ["io.elementary.w".to_string()].into_iter().collect::<HashSet<_>>();
+        assert_eq!(detect_desktop(&rules, None, None, &truncated).as_deref(), Some("Pantheon"));
+    }
+
+    #[test]
+    fn detects_session_type() {
+        assert_eq!(detect_session_type(Some("wayland"), false, false, false), "Wayland");
+        assert_eq!(detect_session_type(Some("tty"), false, true, false), "X11");
+        assert_eq!(detect_session_type(None, false, false, true), "Headless");
+        assert_eq!(detect_session_type(None, false, false, false), "TTY");
+    }
+
+    #[test]
+    fn reads_theme_settings() {
+        let root = fake_tree("session-config", &[
+            ("gtk-3.0/settings.ini", "[Settings]\ngtk-theme-name=Adwaita-dark\ngtk-icon-theme-name=Papirus\ngtk-font-name=Cantarell 11\n"),
+            ("gtk-4.0/settings.ini", "[Settings]\ngtk-theme-name=Ignored\n"),
+            ("kdeglobals", "[General]\nfont=Noto Sans,10,-1,5,50,0,0,0,0,0\n\n[Icons]\nTheme=breeze-dark\n\n[KDE]\nwidgetStyle=Breeze\n"),
+        ]);
+
+        let mut session = SessionInfo::default();
+        read_gtk_settings(&root, &mut session);
+        read_qt_settings(&root, &mut session);
+
+        assert_eq!(session.gtk_theme.as_deref(), Some("Adwaita-dark"));
+        assert_eq!(session.gtk_icon_theme.as_deref(), Some("Papirus"));
+        assert_eq!(session.gtk_font.as_deref(), Some("Cantarell 11"));
+        assert_eq!(session.qt_style.as_deref(), Some("Breeze"));
+        assert_eq!(session.qt_icon_theme.as_deref(), Some("breeze-dark"));
+        assert_eq!(session.qt_font.as_deref(), Some("Noto Sans 10"));
+    }
+}
@@ -0,0 +1,459 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use sysinfo::{Disks, System};
+use crate::cli::args::Cli;
+use crate::system::component::Component;
+use crate::system::storage::{BlockDevice, BtrfsFilesystem, MountUsage, Partition, RaidArray, StorageInfo, Volume};
+use crate::system_info::grabber::info_grab::Grabber;
+use crate::utils::error::WMIError;
+use crate::utils::sysfs::{read_number, read_string};
+
+pub const BLOCK_PATH: &str = "/sys/block";
+pub const MDSTAT_PATH: &str = "/proc/mdstat";
+pub const BTRFS_PATH: &str = "/sys/fs/btrfs";
+
+// /sys/block sizes are always counted in 512 byte sectors, whatever the device sector size.
+const SECTOR_SIZE: u64 = 512;
+
+pub struct StorageGrabber;
+
+impl Grabber for StorageGrabber {
+    fn grab(_sys: &System, cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        // Mounted filesystems by device name, e.g. "nvme0n1p2".
+        let mut mounts = HashMap::<String, Vec<MountUsage>>::new();
+        for disk in Disks::new_with_refreshed_list().list() {
+            let name = disk.name().to_string_lossy().to_string();
+            let Some(device) = name.strip_prefix("/dev/") else {
+                continue;
+            };
+
+            mounts.entry(device.to_string()).or_default().push(MountUsage {
+                mount_point: disk.mount_point().to_string_lossy().to_string(),
+                file_system: disk.file_system().to_string_lossy().to_string(),
+                total_space: disk.total_space(),
+                available_space: disk.available_space(),
+            });
+        }
+
+        let devices = read_devices(Path::new(BLOCK_PATH), &mounts, cli.show_serials);
+        if devices.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mdstat = fs::read_to_string(MDSTAT_PATH).unwrap_or_default();
+        let volumes = read_volumes(Path::new(BLOCK_PATH), &mdstat, &mounts);
+        let btrfs = read_btrfs(Path::new(BTRFS_PATH), &volumes, &mounts);
+
+        Ok(vec![Box::new(StorageInfo { devices, volumes, btrfs }) as Box<dyn Component>])
+    }
+}
+
+/// Read the physical block devices under a /sys/block directory. Loop, zram, device mapper and md
+/// devices have no `device` link and are left out, as are empty drives such as card readers.
+fn read_devices(block_root: &Path, mounts: &HashMap<String, Vec<MountUsage>>, serials: bool) -> Vec<BlockDevice> {
+    let Ok(entries) = fs::read_dir(block_root) else {
+        return Vec::new();
+    };
+
+    let mut devices = entries.flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let block = block_root.join(&name);
+            let device = block.join("device");
+            // NVMe multipath exposes each path as a hidden device next to the shared namespace.
+            if !device.exists() || read_number::<u8>(&block.join("hidden")) == Some(1) {
+                return None;
+            }
+            let size = read_number::<u64>(&block.join("size")).filter(|size| *size > 0)? * SECTOR_SIZE;
+
+            let attribute = |path: &Path| read_string(path).filter(|value| !value.is_empty());
+            let serial = match serials {
+                true => attribute(&block.join("serial")).or_else(|| attribute(&device.join("serial"))),
+                false => None,
+            };
+
+            Some(BlockDevice {
+                model: attribute(&device.join("model")),
+                // SATA disks behind libata all report "ATA", virtio a PCI vendor ID.
+                vendor: attribute(&device.join("vendor")).filter(|vendor| vendor != "ATA" && !vendor.starts_with("0x")),
+                serial,
+                size,
+                rotational: read_number::<u8>(&block.join("queue/rotational")) == Some(1),
+                removable: read_number::<u8>(&block.join("removable")) == Some(1),
+                transport: transport(&name, &device),
+                logical_sector_size: read_number(&block.join("queue/logical_block_size")).unwrap_or(SECTOR_SIZE),
+                physical_sector_size: read_number(&block.join("queue/physical_block_size")).unwrap_or(SECTOR_SIZE),
+                mounts: mounts.get(&name).cloned().unwrap_or_default(),
+                partitions: read_partitions(&block, mounts),
+                holders: read_names(&block.join("holders")),
+                name,
+            })
+        })
+        .collect::<Vec<_>>();
+    devices.sort_by(|a, b| a.name.cmp(&b.name));
+
+    devices
+}
+
+fn read_partitions(block: &Path, mounts: &HashMap<String, Vec<MountUsage>>) -> Vec<Partition> {
+    let Ok(entries) = fs::read_dir(block) else {
+        return Vec::new();
+    };
+
+    let mut partitions = entries.flatten()
+        .filter_map(|entry| {
+            let number = read_number::<u32>(&entry.path().join("partition"))?;
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            Some((number, Partition {
+                size: read_number::<u64>(&entry.path().join("size")).unwrap_or(0) * SECTOR_SIZE,
+                mounts: mounts.get(&name).cloned().unwrap_or_default(),
+                holders: read_names(&entry.path().join("holders")),
+                name,
+            }))
+        })
+        .collect::<Vec<_>>();
+    partitions.sort_by_key(|(number, _)| *number);
+
+    partitions.into_iter().map(|(_, partition)| partition).collect()
+}
+
+/// Read the device mapper and md devices under a /sys/block directory, with md array state taken
+/// from the content of /proc/mdstat.
+fn read_volumes(block_root: &Path, mdstat: &str, mounts: &HashMap<String, Vec<MountUsage>>) -> Vec<Volume> {
+    let Ok(entries) = fs::read_dir(block_root) else {
+        return Vec::new();
+    };
+
+    let mut arrays = parse_mdstat(mdstat);
+    let mut volumes = entries.flatten()
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| name.starts_with("dm-") || name.starts_with("md"))
+        .map(|name| {
+            let block = block_root.join(&name);
+            let label = read_string(&block.join("dm/name"));
+            // dm UUIDs are prefixed by the subsystem that created the device, e.g. "LVM-" or "CRYPT-LUKS2-".
+            let uuid = read_string(&block.join("dm/uuid")).unwrap_or_default();
+            let kind = if name.starts_with("md") {
+                "RAID".to_string()
+            } else if uuid.starts_with("LVM-") {
+                "LVM".to_string()
+            } else if let Some(crypt) = uuid.strip_prefix("CRYPT-") {
+                match crypt.split('-').next() {
+                    Some(format @ ("LUKS1" | "LUKS2")) => format!("crypt {}", format),
+                    _ => "crypt".to_string(),
+                }
+            } else if uuid.starts_with("mpath-") {
+                "multipath".to_string()
+            } else {
+                "dm".to_string()
+            };
+
+            // Device mapper devices are mounted through their /dev/mapper link.
+            let mounts = mounts.get(&name)
+                .or_else(|| mounts.get(&format!("mapper/{}", label.as_deref()?)))
+                .cloned()
+                .unwrap_or_default();
+
+            Volume {
+                label,
+                kind,
+                size: read_number::<u64>(&block.join("size")).unwrap_or(0) * SECTOR_SIZE,
+                members: read_names(&block.join("slaves")),
+                raid: arrays.remove(&name),
+                mounts,
+                holders: read_names(&block.join("holders")),
+                name,
+            }
+        })
+        .collect::<Vec<_>>();
+    volumes.sort_by(|a, b| a.name.cmp(&b.name));
+
+    volumes
+}
+
+/// Parse /proc/mdstat, e.g.
+/// ```text
+/// md1 : active raid5 sdc1[3](F) sdd1[1] sde1[0]
+///       1953260544 blocks super 1.2 level 5, 512k chunk, algorithm 2 [3/2] [UU_]
+///       [==>..................]  recovery = 12.6% (123456/976630272) finish=80.1min speed=150000K/sec
+/// ```
+fn parse_mdstat(mdstat: &str) -> HashMap<String, RaidArray> {
+    let mut arrays = HashMap::new();
+    let mut lines = mdstat.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let Some((name, description)) = line.split_once(" : ") else {
+            continue;
+        };
+        if name.starts_with("Personalities") || name.starts_with("unused") {
+            continue;
+        }
+
+        // State, optional read-only flag, level, then members such as "sdc1[3](F)". Inactive
+        // arrays have no level.
+        let fields = description.split_whitespace().filter(|field| !field.starts_with('(')).collect::<Vec<_>>();
+        let members = fields.iter().filter(|field| field.contains('[')).collect::<Vec<_>>();
+        let mut array = RaidArray {
+            level: fields.iter()
+                .find(|field| !field.contains('[') && !matches!(**field, "active" | "inactive"))
+                .unwrap_or(&"unknown")
+                .to_string(),
+            failed: members.iter()
+                .filter(|member| member.ends_with("(F)"))
+                .filter_map(|member| member.split('[').next())
+                .map(|member| member.to_string())
+                .collect(),
+            ..Default::default()
+        };
+
+        // Details are indented until the blank line ending the array.
+        while let Some(detail) = lines.next_if(|line| line.starts_with(' ')) {
+            let detail = detail.trim();
+            if let Some(counts) = detail.split_whitespace().find(|field| field.starts_with('[') && field.contains('/')) {
+                let (devices, active) = counts.trim_matches(|c| c == '[' || c == ']').split_once('/').unwrap_or_default();
+                array.devices = devices.parse().unwrap_or(0);
+                array.active = active.parse().unwrap_or(0);
+            }
+            // e.g. "[==>....]  recovery = 12.6% (...)" or "resync=DELAYED"
+            let operation = ["recovery", "resync", "check", "reshape"].into_iter()
+                .find_map(|operation| Some((operation, detail.split_once(operation)?.1)));
+            if let Some((operation, progress)) = operation {
+                let progress = progress.trim_start_matches([' ', '=']).split_whitespace().next().unwrap_or_default();
+                array.sync = Some(format!("{} {}", operation, progress));
+            }
+        }
+
+        arrays.insert(name.trim().to_string(), array);
+    }
+
+    arrays
+}
+
+/// Read the btrfs filesystems under /sys/fs/btrfs that span more than one device.
+fn read_btrfs(btrfs_root: &Path, volumes: &[Volume], mounts: &HashMap<String, Vec<MountUsage>>) -> Vec<BtrfsFilesystem> {
+    let Ok(entries) = fs::read_dir(btrfs_root) else {
+        return Vec::new();
+    };
+
+    let mut filesystems = entries.flatten()
+        .filter_map(|entry| {
+            let devices = read_names(&entry.path().join("devices"));
+            if devices.len() < 2 {
+                return None;
+            }
+
+            // Mounted through any member, by kernel name or /dev/mapper name.
+            let mut filesystem_mounts = devices.iter()
+                .flat_map(|device| {
+                    let label = volumes.iter().find(|volume| &volume.name == device).and_then(|volume| volume.label.as_ref());
+                    mounts.get(device).or_else(|| mounts.get(&format!("mapper/{}", label?))).cloned().unwrap_or_default()
+                })
+                .collect::<Vec<_>>();
+            filesystem_mounts.dedup_by(|a, b| a.mount_point == b.mount_point);
+
+            Some(BtrfsFilesystem {
+                uuid: entry.file_name().to_string_lossy().to_string(),
+                label: read_string(&entry.path().join("label")).filter(|label| !label.is_empty()),
+                // The data allocation directory holds one subdirectory named after the profile.
+                profile: read_names(&entry.path().join("allocation/data")).into_iter()
+                    .find(|name| entry.path().join("allocation/data").join(name).is_dir()),
+                devices,
+                mounts: filesystem_mounts,
+            })
+        })
+        .collect::<Vec<_>>();
+    filesystems.sort_by(|a, b| a.uuid.cmp(&b.uuid));
+
+    filesystems
+}
+
+/// Sorted entry names of a directory such as holders or slaves.
+fn read_names(directory: &Path) -> Vec<String> {
+    let mut names = fs::read_dir(directory).into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+    names.sort();
+
+    names
+}
+
+/// Bus the device is attached through, from its name or the path its `device` link points to,
+/// e.g. ".../usb2/2-1/2-1:1.0/host0/target0:0:0/0:0:0:0" for a USB drive.
+fn transport(name: &str, device: &Path) -> Option<String> {
+    let transport = if name.starts_with("nvme") {
+        match read_string(&device.join("transport")).as_deref() {
+            Some("tcp") => "NVMe/TCP",
+            Some("rdma") => "NVMe/RDMA",
+            Some("fc") => "NVMe/FC",
+            _ => "NVMe",
+        }
+    } else if name.starts_with("vd") {
+        "virtio"
+    } else if name.starts_with("xvd") {
+        "Xen"
+    } else if name.starts_with("mmcblk") {
+        "MMC"
+    } else {
+        let path = fs::canonicalize(device).ok()?.to_string_lossy().to_string();
+        if path.contains("/usb") {
+            "USB"
+        } else if path.contains("/ata") {
+            "SATA"
+        } else if path.contains("/end_device-") {
+            "SAS"
+        } else if path.contains("/virtio") {
+            "virtio"
+        } else {
+            "SCSI"
+        }
+    };
+
+    Some(transport.to_string())
+}
+
+// Symlinks make the tests Unix only, which the code under test is anyway.
+#[cfg(all(test, unix))]
+mod tests {
+    use std::collections::HashMap;
+    use std::os::unix::fs::symlink;
+    use crate::system::storage::MountUsage;
+    use crate::system_info::storage_grabber::{parse_mdstat, read_btrfs, read_devices, read_volumes};
+    use crate::utils::sysfs::fake_tree;
+
+    #[test]
+    fn reads_block_devices() {
+        let root = fake_tree("storage-devices", &[
+            ("block/nvme0n1/device/model", "Samsung SSD 980 PRO 1TB\n"),
+            ("block/nvme0n1/device/serial", "S5GXNF0R123456\n"),
+            ("block/nvme0n1/device/transport", "pcie\n"),
+            ("block/nvme0n1/size", "1953525168\n"),
+            ("block/nvme0n1/removable", "0\n"),
+            ("block/nvme0n1/queue/rotational", "0\n"),
+            ("block/nvme0n1/queue/logical_block_size", "512\n"),
+            ("block/nvme0n1/queue/physical_block_size", "4096\n"),
+            ("block/nvme0n1/nvme0n1p2/partition", "2\n"),
+            ("block/nvme0n1/nvme0n1p2/size", "1952475136\n"),
+            ("block/nvme0n1/nvme0n1p1/partition", "1\n"),
+            ("block/nvme0n1/nvme0n1p1/size", "1048576\n"),
+            ("block/sda/size", "15633408\n"),
+            ("block/sda/removable", "1\n"),
+            ("block/sda/queue/rotational", "1\n"),
+            ("devices/pci0000:00/0000:00:14.0/usb2/2-1/host0/target0:0:0/0:0:0:0/model", "Flash Disk\n"),
+            ("devices/pci0000:00/0000:00:14.0/usb2/2-1/host0/target0:0:0/0:0:0:0/vendor", "SanDisk\n"),
+            ("block/sr0/size", "0\n"),
+            ("block/sr0/device/model", "DVD-RW\n"),
+            ("block/loop0/size", "131072\n"),
+            ("block/nvme0c0n1/device/model", "Samsung SSD 980 PRO 1TB\n"),
+            ("block/nvme0c0n1/size", "1953525168\n"),
+            ("block/nvme0c0n1/hidden", "1\n"),
+        ]);
+        symlink(root.join("devices/pci0000:00/0000:00:14.0/usb2/2-1/host0/target0:0:0/0:0:0:0"), root.join("block/sda/device")).unwrap();
+
+        let mounts = HashMap::from([("nvme0n1p2".to_string(), vec![MountUsage {
+            mount_point: "/".into(),
+            file_system: "ext4".into(),
+            total_space: 980 * 1024u64.pow(3),
+            available_space: 500 * 1024u64.pow(3),
+        }])]);
+
+        let devices = read_devices(&root.join("block"), &mounts, false);
+        assert_eq!(devices.iter().map(|device| device.name.as_str()).collect::<Vec<_>>(), vec!["nvme0n1", "sda"]);
+
+        let nvme = &devices[0];
+        assert_eq!(nvme.size, 1953525168 * 512);
+        assert_eq!(nvme.kind(), "NVMe SSD");
+        assert_eq!(nvme.serial, None);
+        assert_eq!((nvme.logical_sector_size, nvme.physical_sector_size), (512, 4096));
+        assert_eq!(nvme.partitions.iter().map(|partition| partition.name.as_str()).collect::<Vec<_>>(), vec!["nvme0n1p1", "nvme0n1p2"]);
+        assert_eq!(nvme.partitions[1].mounts[0].mount_point, "/");
+
+        let usb = &devices[1];
+        assert_eq!(usb.kind(), "USB HDD");
+        assert_eq!(usb.vendor.as_deref(), Some("SanDisk"));
+        assert!(usb.removable);
+
+        let devices = read_devices(&root.join("block"), &mounts, true);
+        assert_eq!(devices[0].serial.as_deref(), Some("S5GXNF0R123456"));
+    }
+
+    #[test]
+    fn parses_mdstat() {
+        let arrays = parse_mdstat("Personalities : [raid1] [raid6] [raid5] [raid4]
+md1 : active raid5 sdc1[3](F) sdd1[1] sde1[0]
+      1953260544 blocks super 1.2 level 5, 512k chunk, algorithm 2 [3/2] [UU_]
+      [==>..................]  recovery = 12.6% (123456/976630272) finish=80.1min speed=150000K/sec
+      bitmap: 2/8 pages [8KB], 65536KB chunk
+
+md0 : active raid1 sdb1[1] sda1[0]
+      976630464 blocks super 1.2 [2/2] [UU]
+
+md127 : inactive sdf[0](S)
+      976630488 blocks super 1.2
+
+unused devices: <none>
+");
+
+        let md1 = &arrays["md1"];
+        assert_eq!((md1.level.as_str(), md1.devices, md1.active), ("raid5", 3, 2));
+        assert_eq!(md1.failed, vec!["sdc1"]);
+        assert_eq!(md1.sync.as_deref(), Some("recovery 12.6%"));
+        assert!(md1.degraded());
+
+        let md0 = &arrays["md0"];
+        assert_eq!((md0.level.as_str(), md0.devices, md0.active), ("raid1", 2, 2));
+        assert_eq!(md0.sync, None);
+        assert!(!md0.degraded());
+
+        assert_eq!(arrays["md127"].level, "unknown");
+        assert_eq!(arrays.len(), 3);
+    }
+
+    #[test]
+    fn reads_luks_over_lvm() {
+        let root = fake_tree("storage-volumes", &[
+            ("block/nvme0n1/nvme0n1p3/holders/dm-0", ""),
+            ("block/dm-0/size", "1048576\n"),
+            ("block/dm-0/dm/name", "luks-0b1c9f3e\n"),
+            ("block/dm-0/dm/uuid", "CRYPT-LUKS2-0b1c9f3e4d5a4b6c8d7e9f0a1b2c3d4e-luks-0b1c9f3e\n"),
+            ("block/dm-0/slaves/nvme0n1p3", ""),
+            ("block/dm-0/holders/dm-1", ""),
+            ("block/dm-1/size", "524288\n"),
+            ("block/dm-1/dm/name", "vg0-root\n"),
+            ("block/dm-1/dm/uuid", "LVM-4tOqv3Xn0aP6tGJ2\n"),
+            ("block/dm-1/slaves/dm-0", ""),
+            ("block/loop0/size", "131072\n"),
+            ("fs/btrfs/5c6f2a1e-0000-4000-8000-000000000001/devices/sda", ""),
+            ("fs/btrfs/5c6f2a1e-0000-4000-8000-000000000001/devices/dm-1", ""),
+            ("fs/btrfs/5c6f2a1e-0000-4000-8000-000000000001/label", "data\n"),
+            ("fs/btrfs/5c6f2a1e-0000-4000-8000-000000000001/allocation/data/total_bytes", "0\n"),
+            ("fs/btrfs/5c6f2a1e-0000-4000-8000-000000000001/allocation/data/raid1/total_bytes", "0\n"),
+            ("fs/btrfs/5c6f2a1e-0000-4000-8000-000000000002/devices/sdc", ""),
+        ]);
+        let mounts = HashMap::from([("mapper/vg0-root".to_string(), vec![MountUsage {
+            mount_point: "/srv".into(),
+            file_system: "btrfs".into(),
+            ..Default::default()
+        }])]);
+
+        let volumes = read_volumes(&root.join("block"), "", &mounts);
+        assert_eq!(volumes.iter().map(|volume| volume.name.as_str()).collect::<Vec<_>>(), vec!["dm-0", "dm-1"]);
+        assert_eq!(volumes[0].kind, "crypt LUKS2");
+        assert_eq!(volumes[0].members, vec!["nvme0n1p3"]);
+        assert_eq!(volumes[0].holders, vec!["dm-1"]);
+        assert_eq!(volumes[1].kind, "LVM");
+        assert_eq!(volumes[1].label.as_deref(), Some("vg0-root"));
+        assert_eq!(volumes[1].size, 512 * 512 * 1024);
+        assert_eq!(volumes[1].mounts[0].mount_point, "/srv");
+
+        let btrfs = read_btrfs(&root.join("fs/btrfs"), &volumes, &mounts);
+        assert_eq!(btrfs.len(), 1);
+        assert_eq!(btrfs[0].label.as_deref(), Some("data"));
+        assert_eq!(btrfs[0].profile.as_deref(), Some("raid1"));
+        assert_eq!(btrfs[0].devices, vec!["dm-1", "sda"]);
+        assert_eq!(btrfs[0].mounts[0].mount_point, "/srv");
+    }
+}
@@ -0,0 +1,228 @@
+use std::env;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::time::Duration;
+use sysinfo::{Pid, System};
+use crate::cli::args::Cli;
+use crate::system::component::Component;
+use crate::system::terminal::{ShellInfo, TerminalInfo};
+use crate::system_info::grabber::info_grab::Grabber;
+use crate::utils::command::output_with_timeout;
+use crate::utils::error::WMIError;
+
+const VERSION_TIMEOUT: Duration = Duration::from_millis(500);
+
+// Process name and whether `--version` is understood.
+const SHELLS: [(&str, bool); 17] = [
+    ("bash", true), ("zsh", true), ("fish", true), ("sh", false), ("dash", false),
+    ("ksh", true), ("mksh", false), ("tcsh", true), ("csh", false), ("nu", true),
+    ("elvish", true), ("xonsh", true), ("ion", true), ("osh", true), ("pwsh", true),
+    ("powershell", false), ("cmd", false),
+];
+
+// Process name, display name and whether `--version` is understood.
+const TERMINALS: [(&str, &str, bool); 24] = [
+    ("gnome-terminal-", "GNOME Terminal", false),
+    ("kgx", "GNOME Console", true),
+    ("ptyxis-agent", "Ptyxis", false),
+    ("ptyxis", "Ptyxis", true),
+    ("konsole", "Konsole", true),
+    ("yakuake", "Yakuake", true),
+    ("xfce4-terminal", "Xfce Terminal", true),
+    ("mate-terminal", "MATE Terminal", true),
+    ("lxterminal", "LXTerminal", true),
+    ("qterminal", "QTerminal", true),
+    ("tilix", "Tilix", true),
+    ("terminator", "Terminator", true),
+    ("alacritty", "Alacritty", true),
+    ("kitty", "kitty", true),
+    ("wezterm-gui", "WezTerm", true),
+    ("foot", "foot", true),
+    ("ghostty", "Ghostty", true),
+    ("xterm", "XTerm", true),
+    ("urxvt", "rxvt-unicode", false),
+    ("st", "st", false),
+    ("WindowsTerminal", "Windows Terminal", false),
+    ("code", "Visual Studio Code", false),
+    ("sshd", "SSH", false),
+    ("login", "TTY", false),
+];
+
+pub struct TerminalGrabber;
+
+impl Grabber for TerminalGrabber {
+    fn grab(sys: &System, _cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        let mut terminal_info = Vec::new();
+
+        let chain = parent_chain(sys);
+        let var = |name: &str| env::var(name).ok().filter(|value| !value.is_empty());
+
+        // Prefer the running shell, fall back to the login shell in SHELL.
+        let shell = find_shell(&chain).or_else(|| var("SHELL").map(|shell| {
+            let name = Path::new(&shell).file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            let knows_version = SHELLS.iter().any(|(shell, version)| *shell == name && *version);
+            (name, Some(PathBuf::from(shell)), knows_version)
+        }));
+        if let Some((name, exe, knows_version)) = shell {
+            terminal_info.push(Box::new(ShellInfo {
+                version: exe.as_ref().filter(|_| knows_version).and_then(|exe| version_of(exe)),
+                path: exe.map(|exe| exe.to_string_lossy().to_string()),
+                name,
+            }) as Box<dyn Component>);
+        }
+
+        let term_program = var("TERM_PROGRAM");
+        let terminal = find_terminal(&chain)
+            .map(|(name, exe, knows_version)| {
+                // Terminals exporting their own version are cheaper to ask than spawning them.
+                let version = var("TERM_PROGRAM_VERSION").filter(|_| term_program.is_some())
+                    .or_else(|| var("KONSOLE_VERSION").filter(|_| name == "Konsole"))
+                    .or_else(|| exe.filter(|_| knows_version).and_then(|exe| version_of(&exe)));
+                (name, version)
+            })
+            .or_else(|| term_program.clone().map(|name| (name, var("TERM_PROGRAM_VERSION"))))
+            .or_else(|| var("TERM").map(|term| (term, None)));
+
+        if let Some((name, version)) = terminal {
+            let (columns, rows) = terminal_size().unzip();
+            terminal_info.push(Box::new(TerminalInfo {
+                name,
+                version,
+                columns,
+                rows,
+                colors: color_support(var("COLORTERM").as_deref(), var("TERM").as_deref(), term_program.as_deref()),
+                term: var("TERM"),
+            }) as Box<dyn Component>);
+        }
+
+        Ok(terminal_info)
+    }
+}
+
+/// Name and executable of each ancestor of this process, nearest first.
+fn parent_chain(sys: &System) -> Vec<(String, Option<PathBuf>)> {
+    let mut chain = Vec::new();
+    let mut pid = sys.process(Pid::from_u32(process::id())).and_then(|process| process.parent());
+
+    while let Some(process) = pid.and_then(|pid| sys.process(pid)) {
+        // Login shells are started with a leading dash, e.g. "-bash".
+        let name = process.name().to_string_lossy();
+        let name = name.trim_start_matches('-').trim_end_matches(".exe").to_string();
+        chain.push((name, process.exe().map(|exe| exe.to_path_buf())));
+
+        pid = process.parent().filter(|parent| chain.len() < 64 && *parent != process.pid());
+    }
+
+    chain
+}
+
+fn find_shell(chain: &[(String, Option<PathBuf>)]) -> Option<(String, Option<PathBuf>, bool)> {
+    chain.iter().find_map(|(name, exe)| {
+        SHELLS.iter()
+            .find(|(shell, _)| shell == name)
+            .map(|(_, knows_version)| (name.clone(), exe.clone(), *knows_version))
+    })
+}
+
+fn find_terminal(chain: &[(String, Option<PathBuf>)]) -> Option<(String, Option<PathBuf>, bool)> {
+    chain.iter().find_map(|(name, exe)| {
+        TERMINALS.iter()
+            .find(|(process_name, _, _)| process_name == name)
+            .map(|(_, display_name, knows_version)| (display_name.to_string(), exe.clone(), *knows_version))
+    })
+}
+
+fn version_of(exe: &Path) -> Option<String> {
+    let output = output_with_timeout(Command::new(exe).arg("--version").stdin(Stdio::null()), VERSION_TIMEOUT)?;
+
+    extract_version(&output)
+}
+
+/// First version-like token of a `--version` output, e.g. "GNU bash, version 5.2.15(1)-release" -> "5.2.15".
+fn extract_version(output: &str) -> Option<String> {
+    output.split_whitespace()
+        .map(|token| token.trim_start_matches('v'))
+        .map(|token| token.split(|c: char| !c.is_ascii_digit() && c != '.').next().unwrap_or_default())
+        .map(|token| token.trim_end_matches('.'))
+        .find(|token| token.contains('.') && token.starts_with(|c: char| c.is_ascii_digit()))
+        .map(|token| token.to_string())
+}
+
+/// Columns and rows of the controlling terminal.
+fn terminal_size() -> Option<(u16, u16)> {
+    let from_env = env::var("COLUMNS").ok().and_then(|columns| columns.parse().ok())
+        .zip(env::var("LINES").ok().and_then(|lines| lines.parse().ok()));
+    if from_env.is_some() {
+        return from_env;
+    }
+
+    // stty prints "rows columns" for the terminal on its standard input.
+    let tty = File::open("/dev/tty").ok()?;
+    let output = output_with_timeout(Command::new("stty").arg("size").stdin(tty), VERSION_TIMEOUT)?;
+    let (rows, columns) = output.trim().split_once(' ')?;
+
+    // Pseudo terminals without a window report 0 0.
+    Some((columns.parse().ok()?, rows.parse().ok()?)).filter(|(columns, rows)| *columns > 0 && *rows > 0)
+}
+
+fn color_support(colorterm: Option<&str>, term: Option<&str>, term_program: Option<&str>) -> Option<String> {
+    let colors = match (colorterm, term, term_program) {
+        (Some("truecolor") | Some("24bit"), _, _) => "Truecolor",
+        (_, _, Some("iTerm.app") | Some("WezTerm") | Some("vscode")) => "Truecolor",
+        (_, Some(term), _) if term.contains("truecolor") || term.contains("direct") => "Truecolor",
+        (_, Some(term), _) if term.contains("256color") => "256",
+        (_, Some("dumb"), _) | (_, None, _) => return None,
+        _ => "16",
+    };
+
+    Some(colors.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use crate::system_info::terminal_grabber::{color_support, extract_version, find_shell, find_terminal};
+
+    #[test]
+    fn finds_shell_and_terminal() {
+        let chain = vec![
+            ("unifetch-wrapper".to_string(), None),
+            ("zsh".to_string(), Some(PathBuf::from("/usr/bin/zsh"))),
+            ("tmux: server".to_string(), None),
+            ("bash".to_string(), Some(PathBuf::from("/usr/bin/bash"))),
+            ("gnome-terminal-".to_string(), Some(PathBuf::from("/usr/libexec/gnome-terminal-server"))),
+            ("systemd".to_string(), None),
+        ];
+
+        let (shell, exe, knows_version) = find_shell(&chain).unwrap();
+        assert_eq!(shell, "zsh");
+        assert_eq!(exe, Some(PathBuf::from("/usr/bin/zsh")));
+        assert!(knows_version);
+
+        let (terminal, _, knows_version) = find_terminal(&chain).unwrap();
+        assert_eq!(terminal, "GNOME Terminal");
+        assert!(!knows_version);
+
+        assert!(find_shell(&chain[5..]).is_none());
+    }
+
+    #[test]
+    fn extracts_version() {
+        assert_eq!(extract_version("GNU bash, version 5.2.15(1)-release (x86_64-pc-linux-gnu)").as_deref(), Some("5.2.15"));
+        assert_eq!(extract_version("zsh 5.9 (x86_64-debian-linux-gnu)").as_deref(), Some("5.9"));
+        assert_eq!(extract_version("fish, version 3.7.1").as_deref(), Some("3.7.1"));
+        assert_eq!(extract_version("alacritty 0.13.1 (fe2a3c5)").as_deref(), Some("0.13.1"));
+        assert_eq!(extract_version("nushell v0.95.0").as_deref(), Some("0.95.0"));
+        assert_eq!(extract_version("unknown option"), None);
+    }
+
+    #[test]
+    fn detects_color_support() {
+        assert_eq!(color_support(Some("truecolor"), Some("xterm-256color"), None).as_deref(), Some("Truecolor"));
+        assert_eq!(color_support(None, Some("xterm-256color"), None).as_deref(), Some("256"));
+        assert_eq!(color_support(None, Some("xterm-256color"), Some("WezTerm")).as_deref(), Some("Truecolor"));
+        assert_eq!(color_support(None, Some("linux"), None).as_deref(), Some("16"));
+        assert_eq!(color_support(None, Some("dumb"), None), None);
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::fs;
+use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::cli::args::{Cli, DisplayStyle, OutputFormat};
+use crate::system::disk::DiskThroughput;
+use crate::system::network::NetworkThroughput;
+
+const NET_DEV_PATH: &str = "/proc/net/dev";
+const DISKSTATS_PATH: &str = "/proc/diskstats";
+
+// /proc/diskstats always counts 512-byte sectors, whatever the device sector size is.
+const SECTOR_SIZE: u64 = 512;
+
+#[derive(Debug, Default)]
+pub struct Throughput {
+    pub networks: HashMap<String, NetworkThroughput>,   // By interface name
+    pub disks: HashMap<String, DiskThroughput>,         // By block device name, e.g. nvme0n1p2
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DiskCounters {
+    reads: u64,
+    sectors_read: u64,
+    writes: u64,
+    sectors_written: u64,
+}
+
+static THROUGHPUT: OnceLock<Throughput> = OnceLock::new();
+
+/// Network and disk rates computed from two samples `cli.interval` milliseconds apart.
+///
+/// Rates are only shown by the detailed style and machine-readable output, other runs skip the
+/// sampling delay. Both grabbers share one sampling window.
+pub fn sample(cli: &Cli) -> Option<&'static Throughput> {
+    if cli.style != DisplayStyle::Detailed && cli.format == OutputFormat::Text {
+        return None;
+    }
+
+    Some(THROUGHPUT.get_or_init(|| {
+        let (net_dev, diskstats) = (read(NET_DEV_PATH), read(DISKSTATS_PATH));
+        let start = Instant::now();
+        thread::sleep(Duration::from_millis(cli.interval));
+        let (net_dev_after, diskstats_after) = (read(NET_DEV_PATH), read(DISKSTATS_PATH));
+        let seconds = start.elapsed().as_secs_f64();
+
+        Throughput {
+            networks: network_rates(&parse_net_dev(&net_dev), &parse_net_dev(&net_dev_after), seconds),
+            disks: disk_rates(&parse_diskstats(&diskstats), &parse_diskstats(&diskstats_after), seconds),
+        }
+    }))
+}
+
+fn read(path: &str) -> String {
+    fs::read_to_string(path).unwrap_or_default()
+}
+
+/// Received and transmitted byte counters by interface.
+fn parse_net_dev(content: &str) -> HashMap<String, (u64, u64)> {
+    // Two header lines, then "iface: rx_bytes rx_packets ... (8 receive fields) tx_bytes ..."
+    content.lines()
+        .skip(2)
+        .filter_map(|line| {
+            let (name, counters) = line.split_once(':')?;
+            let counters = counters.split_whitespace().collect::<Vec<_>>();
+
+            Some((name.trim().to_string(), (counters.first()?.parse().ok()?, counters.get(8)?.parse().ok()?)))
+        })
+        .collect()
+}
+
+fn parse_diskstats(content: &str) -> HashMap<String, DiskCounters> {
+    // major minor name reads reads_merged sectors_read ms_reading writes writes_merged sectors_written ...
+    content.lines()
+        .filter_map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let field = |index: usize| fields.get(index)?.parse::<u64>().ok();
+
+            Some((fields.get(2)?.to_string(), DiskCounters {
+                reads: field(3)?,
+                sectors_read: field(5)?,
+                writes: field(7)?,
+                sectors_written: field(9)?,
+            }))
+        })
+        .collect()
+}
+
+fn network_rates(
+    before: &HashMap<String, (u64, u64)>,
+    after: &HashMap<String, (u64, u64)>,
+    seconds: f64,
+) -> HashMap<String, NetworkThroughput> {
+    after.iter()
+        .filter_map(|(name, (received, transmitted))| {
+            let (received_before, transmitted_before) = before.get(name)?;
+
+            Some((name.clone(), NetworkThroughput {
+                received_per_second: received.saturating_sub(*received_before) as f64 / seconds,
+                transmitted_per_second: transmitted.saturating_sub(*transmitted_before) as f64 / seconds,
+            }))
+        })
+        .collect()
+}
+
+fn disk_rates(
+    before: &HashMap<String, DiskCounters>,
+    after: &HashMap<String, DiskCounters>,
+    seconds: f64,
+) -> HashMap<String, DiskThroughput> {
+    after.iter()
+        .filter_map(|(name, counters)| {
+            let previous = before.get(name)?;
+            let delta = |now: u64, then: u64| now.saturating_sub(then) as f64 / seconds;
+
+            Some((name.clone(), DiskThroughput {
+                read_per_second: delta(counters.sectors_read * SECTOR_SIZE, previous.sectors_read * SECTOR_SIZE),
+                written_per_second: delta(counters.sectors_written * SECTOR_SIZE, previous.sectors_written * SECTOR_SIZE),
+                iops: delta(counters.reads + counters.writes, previous.reads + previous.writes),
+            }))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::throughput::{disk_rates, network_rates, parse_diskstats, parse_net_dev, DiskCounters};
+
+    #[test]
+    fn computes_network_rates() {
+        let header = "Inter-|   Receive                                                |  Transmit\n face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed\n";
+        let before = format!("{}  eth0: 1000 10 0 0 0 0 0 0 500 5 0 0 0 0 0 0\n    lo: 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0\n", header);
+        let after = format!("{}  eth0: 3000 30 0 0 0 0 0 0 1500 15 0 0 0 0 0 0\n", header);
+
+        let (before, after) = (parse_net_dev(&before), parse_net_dev(&after));
+        assert_eq!(before.get("eth0"), Some(&(1000, 500)));
+        assert_eq!(before.get("lo"), Some(&(0, 0)));
+
+        let rates = network_rates(&before, &after, 2f64);
+        assert_eq!(rates.len(), 1);
+        assert_eq!(rates["eth0"].received_per_second, 1000f64);
+        assert_eq!(rates["eth0"].transmitted_per_second, 500f64);
+    }
+
+    #[test]
+    fn computes_disk_rates() {
+        let before = parse_diskstats(" 259       0 nvme0n1 100 0 2000 50 40 0 800 30 0 60 80 0 0 0 0 0 0\n");
+        let after = parse_diskstats(" 259       0 nvme0n1 150 0 4048 70 90 0 1824 40 0 90 110 0 0 0 0 0 0\n");
+
+        assert_eq!(before["nvme0n1"], DiskCounters { reads: 100, sectors_read: 2000, writes: 40, sectors_written: 800 });
+
+        let rates = disk_rates(&before, &after, 1f64);
+        assert_eq!(rates["nvme0n1"].read_per_second, 2048f64 * 512f64);
+        assert_eq!(rates["nvme0n1"].written_per_second, 1024f64 * 512f64);
+        assert_eq!(rates["nvme0n1"].iops, 100f64);
+    }
+}
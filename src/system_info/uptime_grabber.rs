@@ -0,0 +1,53 @@
+use std::path::Path;
+use sysinfo::System;
+use crate::cli::args::Cli;
+use crate::system::component::Component;
+use crate::system::uptime::UptimeInfo;
+use crate::system_info::cgroup;
+use crate::system_info::grabber::info_grab::Grabber;
+use crate::utils::error::WMIError;
+use crate::utils::sysfs::read_string;
+
+const LOADAVG_PATH: &str = "/proc/loadavg";
+
+pub struct UptimeGrabber;
+
+impl Grabber for UptimeGrabber {
+    fn grab(sys: &System, _cli: &Cli) -> Result<Vec<Box<dyn Component>>, WMIError> {
+        let load_average = System::load_average();
+
+        // sysinfo lists threads as processes on Linux, tell them apart with the thread kind.
+        let processes = sys.processes().values().filter(|process| process.thread_kind().is_none()).count();
+        let threads = read_string(Path::new(LOADAVG_PATH)).and_then(|loadavg| parse_thread_count(&loadavg));
+
+        let uptime_info = UptimeInfo {
+            uptime: System::uptime(),
+            boot_time: System::boot_time(),
+            load_average: [load_average.one, load_average.five, load_average.fifteen],
+            cpus: sys.cpus().len(),
+            processes,
+            threads,
+            pids_limit: cgroup::current().pids_limit,
+        };
+
+        Ok(vec![Box::new(uptime_info) as Box<dyn Component>])
+    }
+}
+
+/// Total scheduling entities from /proc/loadavg, e.g. "0.52 0.58 0.59 2/1204 4567".
+fn parse_thread_count(loadavg: &str) -> Option<usize> {
+    let (_running, total) = loadavg.split_whitespace().nth(3)?.split_once('/')?;
+
+    total.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::uptime_grabber::parse_thread_count;
+
+    #[test]
+    fn parses_thread_count() {
+        assert_eq!(parse_thread_count("0.52 0.58 0.59 2/1204 4567\n"), Some(1204));
+        assert_eq!(parse_thread_count("0.52 0.58 0.59"), None);
+    }
+}
@@ -0,0 +1,224 @@
+use std::env;
+use std::path::Path;
+use crate::system::os::Virtualization;
+use crate::utils::sysfs::read_string;
+
+// Vendor signatures of the CPUID hypervisor leaf 0x40000000.
+const CPUID_HYPERVISORS: [(&str, &str); 9] = [
+    ("KVMKVMKVM", "KVM"),
+    ("TCGTCGTCGTCG", "QEMU"),
+    ("VMwareVMware", "VMware"),
+    ("Microsoft Hv", "Hyper-V"),
+    ("VBoxVBoxVBox", "VirtualBox"),
+    ("XenVMMXenVMM", "Xen"),
+    ("bhyve bhyve ", "bhyve"),
+    (" lrpepyh  vr", "Parallels"),
+    ("ACRNACRNACRN", "ACRN"),
+];
+
+// Substrings of the DMI system, board or BIOS vendor and product names.
+const DMI_HYPERVISORS: [(&str, &str); 9] = [
+    ("KVM", "KVM"),
+    ("Amazon EC2", "KVM"),
+    ("QEMU", "QEMU"),
+    ("VMware", "VMware"),
+    ("Virtual Machine", "Hyper-V"),
+    ("VirtualBox", "VirtualBox"),
+    ("innotek GmbH", "VirtualBox"),
+    ("Xen", "Xen"),
+    ("Parallels", "Parallels"),
+];
+
+// Values of the `container` variable set by container managers, see systemd's CONTAINER_INTERFACE.
+const CONTAINER_MANAGERS: [(&str, &str); 5] = [
+    ("docker", "Docker"),
+    ("podman", "Podman"),
+    ("lxc", "LXC"),
+    ("lxc-libvirt", "LXC"),
+    ("systemd-nspawn", "systemd-nspawn"),
+];
+
+/// Detect the virtualization of the running system.
+pub fn current() -> Virtualization {
+    detect(Path::new("/"), |name| env::var(name).ok(), cpuid_hypervisor())
+}
+
+/// Detect virtualization from the files under `root`, the environment and the CPUID hypervisor vendor.
+/// A container is reported along with the machine it runs on, e.g. Docker on a KVM guest.
+pub fn detect(root: &Path, variable: impl Fn(&str) -> Option<String>, cpuid: Option<String>) -> Virtualization {
+    let wsl = read_string(&root.join("proc/version")).and_then(|version| wsl_version(&version));
+    // A Xen dom0 sees the hypervisor it controls, but runs on the host.
+    let dom0 = read_string(&root.join("proc/xen/capabilities")).is_some_and(|capabilities| capabilities.contains("control_d"));
+    let hypervisor = cpuid
+        .filter(|_| !dom0)
+        .and_then(|vendor| CPUID_HYPERVISORS.iter().find(|(signature, _)| vendor.trim_end_matches('\0') == *signature))
+        .map(|(_, name)| name.to_string())
+        .or_else(|| dmi_hypervisor(&root.join("sys/class/dmi/id")))
+        .or_else(|| read_string(&root.join("sys/hypervisor/type")).filter(|kind| kind == "xen" && !dom0).map(|_| "Xen".to_string()));
+    let container = detect_container(root, variable);
+
+    let environment = match (&container, wsl, &hypervisor) {
+        (Some(_), _, _) => "container",
+        (None, Some(_), _) => "wsl",
+        (None, None, Some(_)) => "vm",
+        (None, None, None) => "bare-metal",
+    };
+
+    Virtualization {
+        environment: environment.to_string(),
+        hypervisor,
+        container,
+        wsl,
+    }
+}
+
+/// WSL1 kernels report e.g. "4.4.0-19041-Microsoft", WSL2 ones "5.15.153.1-microsoft-standard-WSL2".
+fn wsl_version(version: &str) -> Option<u8> {
+    if version.contains("WSL2") || version.contains("microsoft-standard") {
+        Some(2)
+    } else if version.contains("Microsoft") {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+fn dmi_hypervisor(dmi: &Path) -> Option<String> {
+    ["sys_vendor", "product_name", "board_vendor", "bios_vendor"].iter()
+        .filter_map(|name| read_string(&dmi.join(name)))
+        .find_map(|value| DMI_HYPERVISORS.iter().find(|(pattern, _)| value.contains(pattern)))
+        .map(|(_, name)| name.to_string())
+}
+
+fn detect_container(root: &Path, variable: impl Fn(&str) -> Option<String>) -> Option<String> {
+    // Pods run on a container runtime as well, Kubernetes is the more useful answer.
+    let init_cgroup = read_string(&root.join("proc/1/cgroup")).unwrap_or_default();
+    if variable("KUBERNETES_SERVICE_HOST").is_some() || init_cgroup.contains("kubepods") {
+        return Some("Kubernetes".to_string());
+    }
+
+    // systemd and most managers pass the manager name to init, systemd copies it to /run.
+    let manager = variable("container").or_else(|| read_string(&root.join("run/systemd/container")));
+    if let Some(manager) = manager.filter(|manager| !manager.is_empty()) {
+        return Some(CONTAINER_MANAGERS.iter()
+            .find(|(value, _)| *value == manager)
+            .map(|(_, name)| name.to_string())
+            .unwrap_or(manager));
+    }
+
+    if root.join(".dockerenv").exists() {
+        return Some("Docker".to_string());
+    }
+    if root.join("run/.containerenv").exists() {
+        return Some("Podman".to_string());
+    }
+
+    // cgroup v1 paths name the runtime, e.g. "/docker/<id>" or "/lxc/<name>".
+    [("docker", "Docker"), ("libpod", "Podman"), ("lxc", "LXC")].iter()
+        .find(|(pattern, _)| init_cgroup.lines().any(|line| line.contains(&format!("/{}", pattern))))
+        .map(|(_, name)| name.to_string())
+}
+
+/// Only trusted on Linux: Windows with virtualization-based security runs as the root partition of
+/// Hyper-V, which sees the hypervisor bit and "Microsoft Hv" on bare metal.
+#[cfg(all(target_os = "linux", any(target_arch = "x86", target_arch = "x86_64")))]
+fn cpuid_hypervisor() -> Option<String> {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::__cpuid;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::__cpuid;
+
+    // __cpuid is only safe to call since Rust 1.87.
+    #[allow(unused_unsafe)]
+    let signature = unsafe { __cpuid(1) };
+    // Bit 31 of ECX is reserved for hypervisors to announce themselves.
+    if signature.ecx & (1 << 31) == 0 {
+        return None;
+    }
+
+    #[allow(unused_unsafe)]
+    let vendor = unsafe { __cpuid(0x4000_0000) };
+    let vendor = [vendor.ebx, vendor.ecx, vendor.edx].iter().flat_map(|register| register.to_le_bytes()).collect::<Vec<_>>();
+
+    Some(String::from_utf8_lossy(&vendor).to_string())
+}
+
+#[cfg(not(all(target_os = "linux", any(target_arch = "x86", target_arch = "x86_64"))))]
+fn cpuid_hypervisor() -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::system_info::virtualization::detect;
+    use crate::utils::sysfs::fake_tree;
+
+    #[test]
+    fn detects_virtual_machine() {
+        let root = fake_tree("virtualization-vm", &[
+            ("proc/version", "Linux version 6.8.0-45-generic (buildd@lcy02-amd64-075)\n"),
+            ("sys/class/dmi/id/sys_vendor", "QEMU\n"),
+        ]);
+
+        let virtualization = detect(&root, |_| None, Some("KVMKVMKVM\0\0\0".into()));
+        assert_eq!(virtualization.environment, "vm");
+        assert_eq!(virtualization.hypervisor.as_deref(), Some("KVM"));
+
+        // Without the CPUID leaf, e.g. on ARM, DMI still tells.
+        let virtualization = detect(&root, |_| None, None);
+        assert_eq!(virtualization.hypervisor.as_deref(), Some("QEMU"));
+        assert_eq!(virtualization.container, None);
+
+        let dom0 = fake_tree("virtualization-xen-dom0", &[
+            ("proc/xen/capabilities", "control_d\n"),
+            ("sys/hypervisor/type", "xen\n"),
+            ("sys/class/dmi/id/sys_vendor", "Supermicro\n"),
+        ]);
+        let virtualization = detect(&dom0, |_| None, Some("XenVMMXenVMM".into()));
+        assert_eq!(virtualization.environment, "bare-metal");
+        assert_eq!(virtualization.hypervisor, None);
+    }
+
+    #[test]
+    fn detects_containers() {
+        let docker = fake_tree("virtualization-docker", &[
+            (".dockerenv", ""),
+            ("proc/1/cgroup", "0::/\n"),
+        ]);
+        let virtualization = detect(&docker, |_| None, None);
+        assert_eq!(virtualization.environment, "container");
+        assert_eq!(virtualization.container.as_deref(), Some("Docker"));
+
+        let nspawn = fake_tree("virtualization-nspawn", &[("run/systemd/container", "systemd-nspawn\n")]);
+        assert_eq!(detect(&nspawn, |_| None, None).container.as_deref(), Some("systemd-nspawn"));
+
+        let lxc = fake_tree("virtualization-lxc", &[("proc/1/cgroup", "12:pids:/lxc/web\n0::/lxc/web\n")]);
+        assert_eq!(detect(&lxc, |_| None, None).container.as_deref(), Some("LXC"));
+
+        let podman = detect(&lxc, |name| (name == "container").then(|| "podman".to_string()), None);
+        assert_eq!(podman.container.as_deref(), Some("Podman"));
+
+        let pod = detect(&docker, |name| (name == "KUBERNETES_SERVICE_HOST").then(|| "10.96.0.1".to_string()), Some("KVMKVMKVM\0\0\0".into()));
+        assert_eq!(pod.container.as_deref(), Some("Kubernetes"));
+        assert_eq!(pod.hypervisor.as_deref(), Some("KVM"));
+    }
+
+    #[test]
+    fn detects_wsl() {
+        let wsl2 = fake_tree("virtualization-wsl2", &[
+            ("proc/version", "Linux version 5.15.153.1-microsoft-standard-WSL2 (root@941d701f84f1) (gcc (GCC) 11.2.0)\n"),
+        ]);
+        let virtualization = detect(&wsl2, |_| None, Some("Microsoft Hv".into()));
+        assert_eq!(virtualization.environment, "wsl");
+        assert_eq!(virtualization.wsl, Some(2));
+        assert_eq!(virtualization.hypervisor.as_deref(), Some("Hyper-V"));
+
+        let wsl1 = fake_tree("virtualization-wsl1", &[
+            ("proc/version", "Linux version 4.4.0-19041-Microsoft (Microsoft@Microsoft.com) (gcc version 5.4.0 (GCC) )\n"),
+        ]);
+        assert_eq!(detect(&wsl1, |_| None, None).wsl, Some(1));
+
+        let bare_metal = fake_tree("virtualization-bare-metal", &[("sys/class/dmi/id/sys_vendor", "Dell Inc.\n")]);
+        assert_eq!(detect(&bare_metal, |_| None, None).environment, "bare-metal");
+    }
+}
@@ -0,0 +1,18 @@
+// The `wmi` crate only exports `WMIError` on Windows, other platforms use this stand-in.
+#[cfg(target_os = "windows")]
+pub use wmi::WMIError;
+
+#[cfg(not(target_os = "windows"))]
+#[derive(Debug)]
+pub enum WMIError {
+    SerdeError(String),
+}
+
+#[cfg(not(target_os = "windows"))]
+impl std::fmt::Display for WMIError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WMIError::SerdeError(message) => write!(f, "{}", message),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+/// Format a byte rate with binary units, e.g. `1.5MB/s`.
+pub fn format_rate(bytes_per_second: f64) -> String {
+    let units = ["B/s", "KB/s", "MB/s", "GB/s"];
+
+    let mut rate = bytes_per_second;
+    let mut unit = 0;
+    while rate >= 1024f64 && unit < units.len() - 1 {
+        rate /= 1024f64;
+        unit += 1;
+    }
+
+    format!("{:.1}{}", rate, units[unit])
+}
+
+/// Format a Unix timestamp as a UTC date and time, e.g. `2025-06-01 08:30:00 UTC`.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let (days, seconds) = (timestamp / 86400, timestamp % 86400);
+
+    // Convert days since 1970-01-01 to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC", year, month, day, seconds / 3600, seconds % 3600 / 60, seconds % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::format::{format_rate, format_timestamp};
+
+    #[test]
+    fn it_works() {
+        assert_eq!(format_rate(512f64), "512.0B/s");
+        assert_eq!(format_rate(1.5 * 1024f64.powi(3)), "1.5GB/s");
+
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(format_timestamp(951782400), "2000-02-29 00:00:00 UTC");
+        assert_eq!(format_timestamp(1748766600), "2025-06-01 08:30:00 UTC");
+    }
+}
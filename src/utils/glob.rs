@@ -0,0 +1,48 @@
+/// Match `text` against a shell style pattern where `*` matches any run of characters, slashes
+/// included, and `?` a single character, e.g. `/snap/*` or `/media/?????`.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let (pattern, text) = (pattern.chars().collect::<Vec<_>>(), text.chars().collect::<Vec<_>>());
+    let (mut p, mut t) = (0, 0);
+    // Position of the last star and the text position it was tried at, to backtrack to.
+    let mut star = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::glob::glob_match;
+
+    #[test]
+    fn it_works() {
+        assert!(glob_match("/snap/*", "/snap/core22/1380"));
+        assert!(glob_match("/", "/"));
+        assert!(!glob_match("/", "/home"));
+        assert!(glob_match("/run/media/*/usb?", "/run/media/alice/usb1"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("/home*", "/home"));
+        assert!(!glob_match("/boot/*", "/boot"));
+        assert!(glob_match("*a*b", "xaxxab"));
+    }
+}
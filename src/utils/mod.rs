@@ -1 +1,9 @@
+pub mod command;
+pub mod error;
+pub mod format;
+pub mod glob;
+pub mod smbios;
+pub mod sqlite;
+pub mod sysfs;
+#[cfg(target_os = "windows")]
 pub mod wmic_check;
\ No newline at end of file
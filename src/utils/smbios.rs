@@ -0,0 +1,111 @@
+//! Walk the SMBIOS structure table (https://www.dmtf.org/standards/smbios) as exported by the kernel
+//! in /sys/firmware/dmi/tables/DMI. Each structure is a formatted area starting with its type,
+//! length and handle, followed by a set of NUL terminated strings ending with an empty one.
+
+const END_OF_TABLE: u8 = 127;
+
+pub struct Structure<'a> {
+    pub kind: u8,
+    pub handle: u16,
+    data: &'a [u8],             // Formatted area, header included
+    strings: Vec<&'a [u8]>,
+}
+
+impl<'a> Structure<'a> {
+    pub fn byte(&self, offset: usize) -> Option<u8> {
+        self.data.get(offset).copied()
+    }
+
+    pub fn word(&self, offset: usize) -> Option<u16> {
+        Some(u16::from_le_bytes(self.data.get(offset..offset + 2)?.try_into().ok()?))
+    }
+
+    pub fn dword(&self, offset: usize) -> Option<u32> {
+        Some(u32::from_le_bytes(self.data.get(offset..offset + 4)?.try_into().ok()?))
+    }
+
+    pub fn qword(&self, offset: usize) -> Option<u64> {
+        Some(u64::from_le_bytes(self.data.get(offset..offset + 8)?.try_into().ok()?))
+    }
+
+    /// The string referenced by the byte at `offset`. Strings are numbered from 1, 0 means none.
+    /// Vendor placeholders such as "Not Specified" are treated as missing.
+    pub fn string(&self, offset: usize) -> Option<String> {
+        let index = self.byte(offset)? as usize;
+        let string = String::from_utf8_lossy(self.strings.get(index.checked_sub(1)?)?).trim().to_string();
+
+        match string.as_str() {
+            "" | "Not Specified" | "Unknown" | "To Be Filled By O.E.M." | "Default string" | "None" => None,
+            _ => Some(string),
+        }
+    }
+}
+
+/// Split a raw table into its structures, stopping at the end-of-table marker or at the first
+/// truncated structure.
+pub fn parse_table(table: &[u8]) -> Vec<Structure<'_>> {
+    let mut structures = Vec::new();
+    let mut offset = 0;
+
+    while let Some(header) = table.get(offset..offset + 4) {
+        let (kind, length) = (header[0], header[1] as usize);
+        let Some(data) = table.get(offset..offset + length).filter(|_| length >= 4) else {
+            break;
+        };
+
+        // The string set ends with two consecutive NUL bytes, even when it is empty.
+        let Some(end) = table[offset + length..].windows(2).position(|bytes| bytes == [0, 0]) else {
+            break;
+        };
+        let string_area = &table[offset + length..offset + length + end];
+        let strings = match string_area.is_empty() {
+            true => Vec::new(),
+            false => string_area.split(|byte| *byte == 0).collect(),
+        };
+
+        structures.push(Structure {
+            kind,
+            handle: u16::from_le_bytes([header[2], header[3]]),
+            data,
+            strings,
+        });
+
+        if kind == END_OF_TABLE {
+            break;
+        }
+        offset += length + end + 2;
+    }
+
+    structures
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::smbios::parse_table;
+
+    #[test]
+    fn parses_structures() {
+        let table = [
+            // Type 1, length 6, handle 0x0001, a string reference and a byte.
+            &[1u8, 6, 0x01, 0x00, 1, 0x2A][..],
+            b"Vendor\0Not Specified\0\0",
+            // Type 32 without strings.
+            &[32, 4, 0x02, 0x00, 0, 0],
+            &[127, 4, 0xFF, 0xFF, 0, 0],
+        ].concat();
+
+        let structures = parse_table(&table);
+        assert_eq!(structures.iter().map(|structure| structure.kind).collect::<Vec<_>>(), vec![1, 32, 127]);
+
+        let system = &structures[0];
+        assert_eq!(system.handle, 1);
+        assert_eq!(system.string(4).as_deref(), Some("Vendor"));
+        assert_eq!(system.byte(5), Some(0x2A));
+        assert_eq!(system.word(4), Some(0x2A01));
+        assert_eq!(system.dword(4), None);
+        assert_eq!(structures[1].string(4), None);
+
+        // A truncated structure ends the walk.
+        assert_eq!(parse_table(&table[..8]).len(), 0);
+    }
+}
@@ -0,0 +1,309 @@
+//! Just enough of the SQLite file format (https://www.sqlite.org/fileformat.html) to count the rows
+//! of a table without linking SQLite. Overflow pages are never followed, which is fine for reading
+//! the type, name and root page columns at the start of schema records.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+const HEADER_MAGIC: &[u8] = b"SQLite format 3\0";
+
+// The last bit of the write-ahead log magic tells whether checksums are computed big-endian.
+const WAL_MAGIC_LITTLE_ENDIAN: u32 = 0x377F0682;
+const WAL_MAGIC_BIG_ENDIAN: u32 = 0x377F0683;
+
+const INTERIOR_TABLE_PAGE: u8 = 0x05;
+const LEAF_TABLE_PAGE: u8 = 0x0D;
+
+/// Number of rows in `table`, or `None` if the file isn't a readable SQLite database. Transactions
+/// committed to the write-ahead log (`<database>-wal`) but not checkpointed yet are included.
+pub fn count_rows(path: &Path, table: &str) -> Option<usize> {
+    let data = fs::read(path).ok()?;
+    let mut wal_path = path.as_os_str().to_owned();
+    wal_path.push("-wal");
+    let wal = fs::read(wal_path).unwrap_or_default();
+
+    Database::new(&data, &wal)?.count_rows(table)
+}
+
+struct Database<'a> {
+    data: &'a [u8],
+    page_size: usize,
+    wal_pages: HashMap<u32, &'a [u8]>,  // Latest committed version of pages in the write-ahead log
+}
+
+#[derive(Debug, PartialEq)]
+enum Column<'a> {
+    Null,
+    Integer(i64),
+    Text(&'a [u8]),
+    Other,
+}
+
+impl<'a> Database<'a> {
+    fn new(data: &'a [u8], wal: &'a [u8]) -> Option<Database<'a>> {
+        if !data.starts_with(HEADER_MAGIC) || data.len() < 100 {
+            return None;
+        }
+
+        // The page size is stored big-endian at offset 16, 1 stands for 65536.
+        let page_size = match u16::from_be_bytes([data[16], data[17]]) {
+            1 => 65536,
+            size => size as usize,
+        };
+
+        Some(Database { data, page_size, wal_pages: read_wal(wal, page_size) })
+    }
+
+    fn page(&self, number: u32) -> Option<&'a [u8]> {
+        if let Some(page) = self.wal_pages.get(&number) {
+            return Some(page);
+        }
+
+        let start = (number as usize).checked_sub(1)? * self.page_size;
+
+        self.data.get(start..start + self.page_size)
+    }
+
+    fn count_rows(&self, table: &str) -> Option<usize> {
+        let mut root_page = None;
+
+        // Page 1 holds the schema table: type, name, tbl_name, rootpage, sql.
+        self.visit_leaf_cells(1, &mut |payload| {
+            let columns = parse_record(payload);
+            if let [Column::Text(b"table"), Column::Text(name), _, Column::Integer(page), ..] = columns.as_slice() {
+                if *name == table.as_bytes() {
+                    root_page = Some(*page as u32);
+                }
+            }
+        }, 0)?;
+
+        let mut rows = 0;
+        self.visit_leaf_cells(root_page?, &mut |_| rows += 1, 0)?;
+
+        Some(rows)
+    }
+
+    /// Call `visit` with the local payload of every cell of the table b-tree rooted at `number`.
+    fn visit_leaf_cells(&self, number: u32, visit: &mut dyn FnMut(&'a [u8]), depth: usize) -> Option<()> {
+        // A corrupted file could point pages at each other.
+        if depth > 32 {
+            return None;
+        }
+
+        let page = self.page(number)?;
+        // Page 1 starts with the 100 byte database header.
+        let header = if number == 1 { 100 } else { 0 };
+        let page_type = *page.get(header)?;
+        let cell_count = u16::from_be_bytes([*page.get(header + 3)?, *page.get(header + 4)?]) as usize;
+
+        match page_type {
+            LEAF_TABLE_PAGE => {
+                for index in 0..cell_count {
+                    let offset = cell_offset(page, header + 8, index)?;
+                    let (payload_size, read) = read_varint(page.get(offset..)?)?;
+                    let (_row_id, read_row_id) = read_varint(page.get(offset + read..)?)?;
+                    let start = offset + read + read_row_id;
+                    let end = (start + payload_size as usize).min(page.len());
+
+                    visit(page.get(start..end)?);
+                }
+            }
+            INTERIOR_TABLE_PAGE => {
+                for index in 0..cell_count {
+                    let offset = cell_offset(page, header + 12, index)?;
+                    let child = u32::from_be_bytes(page.get(offset..offset + 4)?.try_into().ok()?);
+                    self.visit_leaf_cells(child, visit, depth + 1)?;
+                }
+
+                let right_most = u32::from_be_bytes(page.get(header + 8..header + 12)?.try_into().ok()?);
+                self.visit_leaf_cells(right_most, visit, depth + 1)?;
+            }
+            _ => return None,
+        }
+
+        Some(())
+    }
+}
+
+/// Pages of the transactions committed to a write-ahead log. Frames of an unfinished transaction, or
+/// left over from before the log was restarted, fail the salt or checksum check and end the log.
+fn read_wal(wal: &[u8], page_size: usize) -> HashMap<u32, &[u8]> {
+    let mut pages = HashMap::new();
+
+    let Some(header) = wal.get(..32) else {
+        return pages;
+    };
+    let big_endian = match read_u32(header, 0) {
+        WAL_MAGIC_LITTLE_ENDIAN => false,
+        WAL_MAGIC_BIG_ENDIAN => true,
+        _ => return pages,
+    };
+    let mut checksum = wal_checksum(&header[..24], (0, 0), big_endian);
+    if read_u32(header, 8) as usize != page_size || checksum != (read_u32(header, 24), read_u32(header, 28)) {
+        return pages;
+    }
+
+    // Each frame is a 24 byte header: page number, database size in pages for the last frame of a
+    // transaction and 0 otherwise, the salts of the log header and a running checksum.
+    let mut transaction = Vec::new();
+    for frame in wal[32..].chunks_exact(24 + page_size) {
+        let (frame_header, page) = frame.split_at(24);
+        checksum = wal_checksum(page, wal_checksum(&frame_header[..8], checksum, big_endian), big_endian);
+        if frame_header[8..16] != header[16..24] || checksum != (read_u32(frame_header, 16), read_u32(frame_header, 20)) {
+            break;
+        }
+
+        transaction.push((read_u32(frame_header, 0), page));
+        if read_u32(frame_header, 4) != 0 {
+            pages.extend(transaction.drain(..));
+        }
+    }
+
+    pages
+}
+
+/// Fletcher-like checksum of the write-ahead log, over pairs of 32-bit words.
+fn wal_checksum(bytes: &[u8], (mut first, mut second): (u32, u32), big_endian: bool) -> (u32, u32) {
+    let word = |chunk: &[u8]| {
+        let chunk = chunk.try_into().unwrap();
+        match big_endian {
+            true => u32::from_be_bytes(chunk),
+            false => u32::from_le_bytes(chunk),
+        }
+    };
+
+    for pair in bytes.chunks_exact(8) {
+        first = first.wrapping_add(word(&pair[..4])).wrapping_add(second);
+        second = second.wrapping_add(word(&pair[4..])).wrapping_add(first);
+    }
+
+    (first, second)
+}
+
+/// Big-endian 32-bit integer at `offset`, callers check the length.
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+fn cell_offset(page: &[u8], pointers: usize, index: usize) -> Option<usize> {
+    let pointer = pointers + index * 2;
+
+    Some(u16::from_be_bytes([*page.get(pointer)?, *page.get(pointer + 1)?]) as usize)
+}
+
+/// SQLite varint: up to 9 bytes, big-endian, 7 bits per byte except the ninth which uses all 8.
+fn read_varint(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0u64;
+
+    for (index, byte) in bytes.iter().take(9).enumerate() {
+        if index == 8 {
+            return Some(((value << 8) | *byte as u64, 9));
+        }
+
+        value = (value << 7) | (*byte & 0x7F) as u64;
+        if byte & 0x80 == 0 {
+            return Some((value, index + 1));
+        }
+    }
+
+    None
+}
+
+/// Decode the columns of a record, stopping at the first one not fully contained in `payload`.
+fn parse_record(payload: &[u8]) -> Vec<Column<'_>> {
+    let mut columns = Vec::new();
+
+    let Some((header_size, mut header_offset)) = read_varint(payload) else {
+        return columns;
+    };
+    let mut body_offset = header_size as usize;
+
+    while header_offset < header_size as usize {
+        let Some((serial_type, read)) = payload.get(header_offset..).and_then(read_varint) else {
+            break;
+        };
+        header_offset += read;
+
+        let size = match serial_type {
+            0 | 8 | 9 => 0,
+            1..=4 => serial_type as usize,
+            5 => 6,
+            6 | 7 => 8,
+            10 | 11 => 0,
+            _ => (serial_type as usize - 12) / 2,
+        };
+        let Some(value) = payload.get(body_offset..body_offset + size) else {
+            break;
+        };
+        body_offset += size;
+
+        columns.push(match serial_type {
+            0 => Column::Null,
+            1..=6 => {
+                // Big-endian two's complement, sign-extended from the first byte.
+                let initial = if value[0] & 0x80 != 0 { -1i64 } else { 0 };
+                Column::Integer(value.iter().fold(initial, |number, byte| (number << 8) | *byte as i64))
+            }
+            8 => Column::Integer(0),
+            9 => Column::Integer(1),
+            serial_type if serial_type >= 13 && serial_type % 2 == 1 => Column::Text(value),
+            _ => Column::Other,
+        });
+    }
+
+    columns
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use crate::utils::sqlite::{count_rows, parse_record, read_varint, Column};
+
+    #[test]
+    fn reads_varint() {
+        assert_eq!(read_varint(&[0x05]), Some((5, 1)));
+        assert_eq!(read_varint(&[0x81, 0x00]), Some((128, 2)));
+        assert_eq!(read_varint(&[0x80]), None);
+    }
+
+    #[test]
+    fn parses_record() {
+        // Header size 4, text of 5 bytes, 1 byte integer, NULL.
+        let record = [0x04, 0x17, 0x01, 0x00, b't', b'a', b'b', b'l', b'e', 0x02];
+
+        assert_eq!(parse_record(&record), vec![Column::Text(b"table"), Column::Integer(2), Column::Null]);
+    }
+
+    #[test]
+    fn counts_rows() {
+        // rpmdb.sqlite layout with 300 rows, enough for an interior root page.
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/rpmdb.sqlite");
+
+        assert_eq!(count_rows(&path, "Packages"), Some(300));
+        assert_eq!(count_rows(&path, "Name"), Some(300));
+        assert_eq!(count_rows(&path, "Missing"), None);
+        assert_eq!(count_rows(&path.with_file_name("missing.sqlite"), "Packages"), None);
+    }
+
+    #[test]
+    fn replays_wal() {
+        use std::fs;
+        use crate::utils::sysfs::fake_tree;
+
+        // The same database after two transactions left in its write-ahead log: 20 rows added, 1 deleted.
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let database = fixtures.join("rpmdb-wal.sqlite");
+        assert_eq!(count_rows(&database, "Packages"), Some(319));
+
+        // Without its log, or with the last transaction cut short, the database is older.
+        let root = fake_tree("sqlite-wal", &[]);
+        let copy = root.join("rpmdb.sqlite");
+        fs::copy(&database, &copy).unwrap();
+        assert_eq!(count_rows(&copy, "Packages"), Some(300));
+
+        let wal = fs::read(fixtures.join("rpmdb-wal.sqlite-wal")).unwrap();
+        fs::write(root.join("rpmdb.sqlite-wal"), &wal[..wal.len() - 100]).unwrap();
+        assert_eq!(count_rows(&copy, "Packages"), Some(315));
+    }
+}
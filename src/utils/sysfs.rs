@@ -0,0 +1,98 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Read a sysfs/procfs attribute, trimmed. Missing, unreadable or empty files yield `None`.
+pub fn read_string(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    let content = content.trim();
+
+    if content.is_empty() {
+        return None;
+    }
+
+    Some(content.to_string())
+}
+
+/// Read a sysfs/procfs attribute holding a single number.
+pub fn read_number<T: FromStr>(path: &Path) -> Option<T> {
+    read_string(path)?.parse().ok()
+}
+
+/// The bracketed choice of a sysfs selection, e.g. "lzo [zstd] lz4" or "always [madvise] never".
+pub fn selected(choices: &str) -> Option<String> {
+    let start = choices.find('[')?;
+    let end = choices[start..].find(']')?;
+
+    Some(choices[start + 1..start + end].to_string())
+}
+
+/// Parse a kernel CPU list such as "0-3,8,10-11", or a space separated one as in cpufreq, into CPU numbers.
+pub fn parse_cpu_list(list: &str) -> Vec<usize> {
+    list.trim()
+        .split(|separator: char| separator == ',' || separator.is_whitespace())
+        .filter(|range| !range.is_empty())
+        .flat_map(|range| match range.split_once('-') {
+            Some((start, end)) => match (start.parse::<usize>(), end.parse::<usize>()) {
+                (Ok(start), Ok(end)) => (start..=end).collect(),
+                _ => Vec::new(),
+            },
+            None => range.parse().into_iter().collect(),
+        })
+        .collect()
+}
+
+/// Format sorted CPU numbers as a kernel CPU list, collapsing consecutive numbers into ranges.
+pub fn format_cpu_list(cpus: &[usize]) -> String {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+
+    for cpu in cpus {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == *cpu => *end = *cpu,
+            _ => ranges.push((*cpu, *cpu)),
+        }
+    }
+
+    ranges.iter()
+        .map(|(start, end)| match start == end {
+            true => start.to_string(),
+            false => format!("{}-{}", start, end),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Build a throwaway directory tree under the system temp directory for tests.
+#[cfg(test)]
+pub fn fake_tree(name: &str, files: &[(&str, &str)]) -> std::path::PathBuf {
+    let root = std::env::temp_dir().join(format!("unifetch-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+
+    for (path, content) in files {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    fs::create_dir_all(&root).unwrap();
+
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::sysfs::{format_cpu_list, parse_cpu_list, selected};
+
+    #[test]
+    fn parses_selection() {
+        assert_eq!(selected("always [madvise] never").as_deref(), Some("madvise"));
+        assert_eq!(selected("none"), None);
+    }
+
+    #[test]
+    fn parses_cpu_list() {
+        assert_eq!(parse_cpu_list("0-3,8,10-11\n"), vec![0, 1, 2, 3, 8, 10, 11]);
+        assert_eq!(parse_cpu_list("0 1 2 3"), vec![0, 1, 2, 3]);
+        assert_eq!(parse_cpu_list(""), Vec::<usize>::new());
+        assert_eq!(format_cpu_list(&[0, 1, 2, 3, 8, 10, 11]), "0-3,8,10-11");
+    }
+}
@@ -1,5 +1,5 @@
 use std::process::Command;
-use wmi::WMIError;
+use crate::utils::error::WMIError;
 
 fn is_wmic_installed() -> bool {
     let output = Command::new("cmd")